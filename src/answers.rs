@@ -0,0 +1,199 @@
+//! A record of known-correct answers, used to check solutions for regressions.
+//!
+//! Answers are stored in an `answers.toml` file, using a small subset of TOML with one table per
+//! day:
+//!
+//! ```toml
+//! [day8]
+//! part1 = "6611"
+//! part2 = "6619"
+//! ```
+//!
+//! Each profile keeps its own answers file next to its inputs, since every profile has a different
+//! puzzle input and so different answers.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Write};
+use std::path::Path;
+
+use error::{Error, Result};
+
+/// The name of the file answers are stored in.
+pub const FILE_NAME : &str = "answers.toml";
+
+/// The outcome of comparing an answer with the known-correct answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    /// The answer matches the known-correct answer.
+    Pass,
+    /// The answer differs from the known-correct answer, which is given.
+    Fail { expected : String },
+    /// There is no known-correct answer.
+    Unknown
+}
+
+/// The known-correct answers to each part of each day.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::answers::{Answers, Check};
+///
+/// let answers = Answers::parse("[day6]\npart1 = \"5\"\n").unwrap();
+/// assert_eq!(answers.check(6, 1, "5"), Check::Pass);
+/// assert_eq!(answers.check(6, 1, "4"), Check::Fail { expected: "5".to_string() });
+/// assert_eq!(answers.check(6, 2, "4"), Check::Unknown);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    days : BTreeMap<usize, [Option<String>; 2]>
+}
+
+/// Return the value of a TOML string or integer.
+fn parse_value(value : &str) -> Option<String> {
+    if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
+        let mut ret = String::new();
+        let mut chars = value[1..value.len() - 1].chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some('"') => ret.push('"'),
+                    Some('\\') => ret.push('\\'),
+                    Some('n') => ret.push('\n'),
+                    Some('t') => ret.push('\t'),
+                    _ => return None
+                },
+                '"' => return None,
+                c => ret.push(c)
+            }
+        }
+        Some(ret)
+    } else if value.parse::<i64>().is_ok() {
+        Some(value.to_string())
+    } else {
+        None
+    }
+}
+
+/// Return the part number named by a key such as `part1`.
+fn parse_part(key : &str) -> Option<usize> {
+    match key {
+        "part1" => Some(1),
+        "part2" => Some(2),
+        _ => None
+    }
+}
+
+impl Answers {
+    /// Return an empty set of answers.
+    pub fn new() -> Answers {
+        Answers::default()
+    }
+
+    /// Return the answers held in the TOML string `s`.
+    pub fn parse(s : &str) -> Result<Answers> {
+        let mut answers = Answers::new();
+        let mut day = None;
+        for (idx, line) in s.lines().enumerate() {
+            let error = |token : &str, expected| Error::Answers {
+                line: idx + 1,
+                token: token.to_string(),
+                expected
+            };
+            let content = line.trim();
+            if content.is_empty() || content.starts_with('#') {
+                continue;
+            }
+            if content.starts_with('[') && content.ends_with(']') {
+                let name = &content[1..content.len() - 1];
+                let parsed = name.strip_prefix("day").and_then(|n| n.parse().ok());
+                day = Some(parsed.ok_or_else(|| error(name, "a table named `dayN`"))?);
+                continue;
+            }
+            let mut kv = content.splitn(2, '=');
+            let key = kv.next().unwrap_or("").trim();
+            let value = kv.next().unwrap_or("").trim();
+            let part = parse_part(key).ok_or_else(|| error(key, "`part1` or `part2`"))?;
+            let value = parse_value(value).ok_or_else(|| error(value, "a string or integer"))?;
+            let day = day.ok_or_else(|| error(key, "a `[dayN]` table header"))?;
+            answers.set(day, part, &value);
+        }
+
+        Ok(answers)
+    }
+
+    /// Return the answers stored in the file at `path`, or no answers if it does not exist.
+    pub fn load(path : &Path) -> Result<Answers> {
+        let mut contents = String::new();
+        match File::open(path).and_then(|mut f| f.read_to_string(&mut contents)) {
+            Ok(_) => Answers::parse(&contents),
+            Err(ref err) if err.kind() == ErrorKind::NotFound => Ok(Answers::new()),
+            Err(err) => Err(Error::Io { path: path.display().to_string(), message: err.to_string() })
+        }
+    }
+
+    /// Write the answers to the file at `path`, replacing anything already there.
+    pub fn save(&self, path : &Path) -> Result<()> {
+        File::create(path).and_then(|mut f| f.write_all(self.to_string().as_bytes()))
+                          .map_err(|err| Error::Io {
+                              path: path.display().to_string(),
+                              message: err.to_string()
+                          })
+    }
+
+    /// Return the known-correct answer to `part` of `day`, if any.
+    pub fn get(&self, day : usize, part : usize) -> Option<&str> {
+        assert!(part == 1 || part == 2);
+        self.days.get(&day).and_then(|parts| parts[part - 1].as_ref()).map(|a| a.as_str())
+    }
+
+    /// Record `answer` as the known-correct answer to `part` of `day`.
+    pub fn set(&mut self, day : usize, part : usize, answer : &str) {
+        assert!(part == 1 || part == 2);
+        let parts = self.days.entry(day).or_insert([None, None]);
+        parts[part - 1] = Some(answer.to_string());
+    }
+
+    /// Return how `answer` compares with the known-correct answer to `part` of `day`.
+    pub fn check(&self, day : usize, part : usize, answer : &str) -> Check {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Fail { expected: expected.to_string() },
+            None => Check::Unknown
+        }
+    }
+}
+
+/// Formats the answers as TOML, which can be read back with `Answers::parse`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::answers::Answers;
+///
+/// let mut answers = Answers::new();
+/// answers.set(9, 2, "say \"hi\"");
+/// answers.set(1, 1, "3");
+/// assert_eq!(answers.to_string(), "[day1]\npart1 = \"3\"\n\n[day9]\npart2 = \"say \\\"hi\\\"\"\n");
+/// assert_eq!(Answers::parse(&answers.to_string()), Ok(answers));
+/// ```
+impl fmt::Display for Answers {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        for (idx, (day, parts)) in self.days.iter().enumerate() {
+            if idx > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "[day{}]", day)?;
+            for (part, answer) in parts.iter().enumerate() {
+                if let Some(ref answer) = *answer {
+                    let escaped = answer.replace('\\', "\\\\").replace('"', "\\\"")
+                                        .replace('\n', "\\n").replace('\t', "\\t");
+                    writeln!(f, "part{} = \"{}\"", part + 1, escaped)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
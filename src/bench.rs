@@ -0,0 +1,166 @@
+//! Timing of the parsing and solving steps of each day.
+
+use std::fmt;
+use std::hint::black_box;
+use std::time::Instant;
+
+use error::Result;
+use solver::Solution;
+
+/// How many times each step is run when benchmarking.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Config {
+    /// The number of untimed runs made before timing starts.
+    pub warmup : usize,
+    /// The number of timed runs.
+    pub runs : usize
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config { warmup: 1, runs: 10 }
+    }
+}
+
+/// Summary statistics of a set of timed runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    /// The number of timed runs.
+    pub runs : usize,
+    /// The mean time of a run, in seconds.
+    pub mean : f64,
+    /// The sample standard deviation of the time of a run, in seconds.
+    pub stddev : f64
+}
+
+impl Stats {
+    /// Return the statistics of the run times in `samples`, in seconds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::bench::Stats;
+    ///
+    /// let stats = Stats::from_samples(&[1.0, 2.0, 3.0]);
+    /// assert_eq!(stats.runs, 3);
+    /// assert_eq!(stats.mean, 2.0);
+    /// assert_eq!(stats.stddev, 1.0);
+    ///
+    /// assert_eq!(Stats::from_samples(&[4.0]).stddev, 0.0);
+    /// ```
+    pub fn from_samples(samples : &[f64]) -> Stats {
+        let runs = samples.len();
+        let mean = samples.iter().sum::<f64>()/(runs.max(1) as f64);
+        let stddev = if runs > 1 {
+            let variance = samples.iter().map(|x| (x - mean)*(x - mean)).sum::<f64>()/((runs - 1) as f64);
+            variance.sqrt()
+        } else {
+            0.0
+        };
+        Stats { runs, mean, stddev }
+    }
+}
+
+/// Return `seconds` formatted with a unit suited to its magnitude.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::bench::format_seconds;
+///
+/// assert_eq!(format_seconds(2.5), "2.500 s");
+/// assert_eq!(format_seconds(0.0125), "12.500 ms");
+/// assert_eq!(format_seconds(0.0000031), "3.100 µs");
+/// ```
+pub fn format_seconds(seconds : f64) -> String {
+    if seconds >= 1.0 {
+        format!("{:.3} s", seconds)
+    } else if seconds >= 1e-3 {
+        format!("{:.3} ms", seconds*1e3)
+    } else if seconds >= 1e-6 {
+        format!("{:.3} µs", seconds*1e6)
+    } else {
+        format!("{:.3} ns", seconds*1e9)
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ± {}", format_seconds(self.mean), format_seconds(self.stddev))
+    }
+}
+
+/// The timing of each step of a single day.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    /// The day that was timed.
+    pub day : usize,
+    /// The time taken to parse the puzzle input.
+    pub parse : Stats,
+    /// The time taken to solve puzzle #1, excluding parsing.
+    pub part1 : Stats,
+    /// The time taken to solve puzzle #2, excluding parsing.
+    pub part2 : Stats
+}
+
+/// Return the statistics of calling `f` as described by the `config`.
+pub fn measure<T, F : FnMut() -> T>(config : &Config, mut f : F) -> Stats {
+    for _ in 0..config.warmup {
+        black_box(f());
+    }
+    let samples : Vec<f64> = (0..config.runs).map(|_| {
+        let start = Instant::now();
+        black_box(f());
+        start.elapsed().as_secs_f64()
+    }).collect();
+
+    Stats::from_samples(&samples)
+}
+
+/// Return the timing of each step of the `solution` given the `puzzle` input.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::bench::{bench, Config};
+/// use aoc17::day6::Day6;
+///
+/// let config = Config { warmup: 0, runs: 3 };
+/// let timing = bench(&Day6, "0 2 7 0", &config).unwrap();
+/// assert_eq!(timing.day, 6);
+/// assert_eq!(timing.part1.runs, 3);
+/// ```
+pub fn bench(solution : &dyn Solution, puzzle : &str, config : &Config) -> Result<Timing> {
+    // Parse once up front, so that a bad input is reported rather than timed
+    let input = solution.parse(puzzle)?;
+    let parse = measure(config, || solution.parse(puzzle));
+    let part1 = measure(config, || solution.part1(&*input));
+    let part2 = measure(config, || solution.part2(&*input));
+
+    Ok(Timing { day: solution.day(), parse, part1, part2 })
+}
+
+/// Return a JSON document describing the `timings`, with times given in seconds.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::bench::{to_json, Stats, Timing};
+///
+/// let stats = Stats { runs: 2, mean: 0.5, stddev: 0.25 };
+/// let timing = Timing { day: 6, parse: stats, part1: stats, part2: stats };
+/// let stats_json = r#"{"runs": 2, "mean": 0.5, "stddev": 0.25}"#;
+/// assert_eq!(to_json(&[timing]), format!(
+///     "{{\n  \"days\": [\n    {{\"day\": 6, \"parse\": {0}, \"part1\": {0}, \"part2\": {0}}}\n  ]\n}}\n",
+///     stats_json));
+/// ```
+pub fn to_json(timings : &[Timing]) -> String {
+    let stats = |s : &Stats| format!("{{\"runs\": {}, \"mean\": {:?}, \"stddev\": {:?}}}",
+                                     s.runs, s.mean, s.stddev);
+    let days : Vec<String> = timings.iter().map(|t| {
+        format!("    {{\"day\": {}, \"parse\": {}, \"part1\": {}, \"part2\": {}}}",
+                t.day, stats(&t.parse), stats(&t.part1), stats(&t.part2))
+    }).collect();
+
+    format!("{{\n  \"days\": [\n{}\n  ]\n}}\n", days.join(",\n"))
+}
@@ -0,0 +1,309 @@
+extern crate aoc17;
+
+use std::env;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+use aoc17::answers::{self, Answers, Check};
+use aoc17::bench::{self, Config};
+use aoc17::input;
+use aoc17::inputs::{Cache, Status, DEFAULT_PROFILE};
+use aoc17::output::{self, Format, Record};
+use aoc17::registry::{solution, SOLUTIONS};
+use aoc17::solver::Solution;
+use aoc17::{Error, Result};
+
+const USAGE : &str = "Usage:
+    aoc [options] run <day> [<input>]       Run the solutions for a single day
+    aoc [options] run all                   Run the solutions for every day
+    aoc list                                List the days with solutions
+    aoc [options] verify [<day>|all]        Check answers against the known-correct answers
+    aoc [options] record <day>|all          Store answers as the known-correct answers
+    aoc [options] bench [<day>|all]         Time the parsing and solving of each day
+    aoc [options] inputs import <day> <input>
+                                            Copy an input into the cache
+    aoc inputs list                         List the cached inputs
+    aoc [options] inputs verify             Check cached inputs against their hashes
+
+Options:
+    --profile <name>    Use the cached inputs of the given profile
+    --format <format>   Write results of run and verify as plain, json or csv [default: plain]
+    --warmup <n>        Number of untimed runs before benchmarking [default: 1]
+    --runs <n>          Number of timed benchmark runs [default: 10]
+    --json <file>       Also write the benchmark report as JSON to <file>
+
+The input is read from <input> if given, where `-` means standard input. Otherwise, if a profile
+is given, it is read from that profile's cache, or else from `dayN.txt` in the directory named by
+AOC_INPUT_DIR, or in `inputs/` if that is unset.
+
+Known-correct answers are kept in `answers.toml` alongside the inputs: in the profile's cache if a
+profile is given, or else in the input directory.";
+
+/// Options given before or after the command.
+struct Options {
+    profile : Option<String>,
+    format : Format,
+    bench : Config,
+    json : Option<String>
+}
+
+/// Return the puzzle input for `day`.
+///
+/// An explicit `input_arg` takes precedence over the profile's cache, which takes precedence over
+/// the default locations of `input::load`.
+fn load(day : usize, input_arg : Option<&str>, options : &Options) -> Result<String> {
+    match (input_arg, &options.profile) {
+        (None, Some(profile)) => Cache::user()?.load(profile, day),
+        _ => input::load(day, input_arg)
+    }
+}
+
+/// Return a record of solving each part of the `solution`, timing each part.
+fn solve(solution : &dyn Solution, input_arg : Option<&str>, options : &Options) -> Vec<Record> {
+    let day = solution.day();
+    let input = match load(day, input_arg, options).and_then(|puzzle| solution.parse(&puzzle)) {
+        Ok(input) => input,
+        Err(err) => return (1..solution.parts() + 1).map(|part| Record::failed(day, part, &err))
+                                                  .collect()
+    };
+    let mut records = Vec::new();
+    for part in 1..solution.parts() + 1 {
+        let start = Instant::now();
+        let answer = if part == 1 { solution.part1(&*input) } else { solution.part2(&*input) };
+        records.push(match answer {
+            Ok(answer) => Record::solved(day, part, answer, start.elapsed().as_secs_f64()),
+            Err(err) => Record::failed(day, part, &err)
+        });
+    }
+    records
+}
+
+/// Run both puzzles of the `solutions` and print the answers.
+///
+/// Returns false if any puzzle input could not be loaded or solved.
+fn run(solutions : &[&dyn Solution], input_arg : Option<&str>, options : &Options) -> bool {
+    let records : Vec<Record> = solutions.iter().flat_map(|s| solve(*s, input_arg, options)).collect();
+    print!("{}", output::render(options.format, &records));
+    records.iter().all(|r| r.status != output::Status::Error)
+}
+
+/// Return the path of the answers file for the selected profile.
+fn answers_path(options : &Options) -> Result<PathBuf> {
+    let dir = match options.profile {
+        Some(ref profile) => Cache::user()?.profile_dir(profile)?,
+        None => input::input_dir()
+    };
+    Ok(dir.join(answers::FILE_NAME))
+}
+
+/// Run the `solutions` and compare their answers with the known-correct answers.
+///
+/// Returns false if any answer is wrong or any puzzle input could not be loaded or solved.
+fn verify(solutions : &[&dyn Solution], options : &Options) -> Result<bool> {
+    let answers = Answers::load(&answers_path(options)?)?;
+    let mut records : Vec<Record> = solutions.iter().flat_map(|s| solve(*s, None, options)).collect();
+    for record in records.iter_mut() {
+        if let Some(ref answer) = record.answer {
+            record.status = match answers.check(record.day, record.part, answer) {
+                Check::Pass => output::Status::Pass,
+                Check::Fail { expected } => {
+                    record.expected = Some(expected);
+                    output::Status::Fail
+                },
+                Check::Unknown => output::Status::Unknown
+            };
+        }
+    }
+    print!("{}", output::render(options.format, &records));
+    Ok(records.iter().all(|r| r.status != output::Status::Fail && r.status != output::Status::Error))
+}
+
+/// Run the `solutions` and store their answers as the known-correct answers.
+///
+/// Returns false if any puzzle could not be loaded or solved; the answers of the others are still
+/// stored.
+fn record(solutions : &[&dyn Solution], options : &Options) -> Result<bool> {
+    let path = answers_path(options)?;
+    let mut answers = Answers::load(&path)?;
+    let mut success = true;
+    for solution in solutions {
+        for r in solve(*solution, None, options) {
+            match r.answer {
+                Some(answer) => {
+                    answers.set(r.day, r.part, &answer);
+                    println!("Day {} part {}: recorded {}", r.day, r.part, answer);
+                },
+                None => {
+                    println!("Day {} part {}: error: {}", r.day, r.part,
+                             r.error.unwrap_or_default());
+                    success = false;
+                }
+            }
+        }
+    }
+    answers.save(&path)?;
+    Ok(success)
+}
+
+/// Time each step of the `solutions` and print the timings.
+///
+/// Returns false if any puzzle input could not be loaded or solved.
+fn benchmark(solutions : &[&dyn Solution], options : &Options) -> Result<bool> {
+    let mut timings = Vec::new();
+    let mut success = true;
+    println!("{:>4}  {:>24}  {:>24}  {:>24}", "Day", "Parse", "Puzzle #1", "Puzzle #2");
+    for solution in solutions {
+        let day = solution.day();
+        match load(day, None, options).and_then(|p| bench::bench(*solution, &p, &options.bench)) {
+            Ok(timing) => {
+                println!("{:>4}  {:>24}  {:>24}  {:>24}",
+                         day, timing.parse.to_string(), timing.part1.to_string(),
+                         timing.part2.to_string());
+                timings.push(timing);
+            },
+            Err(err) => {
+                println!("{:>4}  error: {}", day, err);
+                success = false;
+            }
+        }
+    }
+    if let Some(ref path) = options.json {
+        File::create(path).and_then(|mut f| f.write_all(bench::to_json(&timings).as_bytes()))
+                          .map_err(|err| Error::Io { path: path.clone(), message: err.to_string() })?;
+    }
+    Ok(success)
+}
+
+/// Copy the input at `input_arg` into the cache for `day` of the selected profile.
+fn import(day : usize, input_arg : &str, options : &Options) -> Result<()> {
+    let profile = options.profile.as_ref().map_or(DEFAULT_PROFILE, |p| p.as_str());
+    let puzzle = input::read(&input::resolve(day, Some(input_arg), None))?;
+    let cache = Cache::user()?;
+    cache.import(profile, day, &puzzle)?;
+    let path = cache.path(profile, day)?;
+    println!("Imported day {} for profile {} to {}", day, profile, path.display());
+    Ok(())
+}
+
+/// Check every cached input, or only those of the selected profile, against their hashes.
+///
+/// Returns false if any input has been modified since it was imported.
+fn verify_inputs(options : &Options) -> Result<bool> {
+    let cache = Cache::user()?;
+    let mut success = true;
+    for entry in cache.list()? {
+        if options.profile.as_ref().is_some_and(|p| *p != entry.profile) {
+            continue;
+        }
+        let status = match cache.verify(&entry.profile, entry.day)? {
+            Status::Verified => "ok".to_string(),
+            Status::Unhashed => "no hash recorded".to_string(),
+            Status::Modified { expected, found } => {
+                success = false;
+                format!("MODIFIED (expected {:016x}, found {:016x})", expected, found)
+            }
+        };
+        println!("{} day {}: {}", entry.profile, entry.day, status);
+    }
+    Ok(success)
+}
+
+fn exit_with_usage() -> ! {
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+/// Return the options in `args`, removing them from it.
+fn parse_options(args : &mut Vec<String>) -> Options {
+    let mut options = Options {
+        profile: None,
+        format: Format::Plain,
+        bench: Config::default(),
+        json: None
+    };
+    while let Some(idx) = args.iter().position(|a| a.starts_with("--")) {
+        let value = if idx + 1 < args.len() { args.remove(idx + 1) } else { exit_with_usage() };
+        match args.remove(idx).as_str() {
+            "--profile" => options.profile = Some(value),
+            "--format" => options.format = value.parse().unwrap_or_else(|_| exit_with_usage()),
+            "--warmup" => options.bench.warmup = parse_count(&value),
+            "--runs" => options.bench.runs = parse_count(&value),
+            "--json" => options.json = Some(value),
+            _ => exit_with_usage()
+        }
+    }
+    options
+}
+
+/// Return the non-negative count in `arg`.
+fn parse_count(arg : &str) -> usize {
+    arg.parse().unwrap_or_else(|_| exit_with_usage())
+}
+
+/// Return the day number in `arg`.
+fn parse_day(arg : &str) -> usize {
+    arg.parse().unwrap_or_else(|_| exit_with_usage())
+}
+
+/// Return the solutions selected by `arg`, which is either a day number or `all`.
+fn select(arg : &str) -> Vec<&'static dyn Solution> {
+    if arg == "all" {
+        return SOLUTIONS.to_vec();
+    }
+    let day = parse_day(arg);
+    match solution(day) {
+        Some(s) => vec![s],
+        None => {
+            eprintln!("No solution for day {}", day);
+            process::exit(1);
+        }
+    }
+}
+
+/// Return the value held by `result`, or print the error and return false.
+fn report(result : Result<bool>) -> bool {
+    result.unwrap_or_else(|err| {
+        eprintln!("Error: {}", err);
+        false
+    })
+}
+
+fn main() {
+    let mut args : Vec<String> = env::args().skip(1).collect();
+    let options = parse_options(&mut args);
+    let args : Vec<&str> = args.iter().map(|s| s.as_str()).collect();
+    let success = match args.as_slice() {
+        ["list"] => {
+            for s in SOLUTIONS.iter() {
+                println!("{}", s.day());
+            }
+            true
+        },
+        ["run", days] => run(&select(days), None, &options),
+        ["run", day, input_arg] if *day != "all" => run(&select(day), Some(input_arg), &options),
+        ["verify"] => report(verify(SOLUTIONS, &options)),
+        ["verify", days] => report(verify(&select(days), &options)),
+        ["record", days] => report(record(&select(days), &options)),
+        ["bench"] => report(benchmark(SOLUTIONS, &options)),
+        ["bench", days] => report(benchmark(&select(days), &options)),
+        ["inputs", "import", day, input_arg] => {
+            report(import(parse_day(day), input_arg, &options).map(|_| true))
+        },
+        ["inputs", "list"] => {
+            report(Cache::user().and_then(|cache| cache.list()).map(|entries| {
+                for entry in entries {
+                    println!("{} day {}", entry.profile, entry.day);
+                }
+                true
+            }))
+        },
+        ["inputs", "verify"] => report(verify_inputs(&options)),
+        _ => exit_with_usage()
+    };
+    if !success {
+        process::exit(1);
+    }
+}
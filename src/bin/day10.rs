@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day10::Day10;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(10, env::args().nth(1).as_deref());
+    let input = solver::or_exit(Day10.parse(&puzzle));
+    solver::print_answers(&Day10, &input);
+}
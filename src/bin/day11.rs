@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day11::Day11;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(11, env::args().nth(1).as_deref());
+    let steps = solver::or_exit(Day11.parse(&puzzle));
+    solver::print_answers(&Day11, &steps);
+}
@@ -1,18 +1,13 @@
 extern crate aoc17;
 
-use std::fs::File;
-use std::io::prelude::*;
+use std::env;
 
-use aoc17::day12::*;
+use aoc17::input;
+use aoc17::day12::Day12;
+use aoc17::solver::{self, Solver};
 
 fn main() {
-    let mut f = File::open("input/day12.txt").expect("Could not open input");
-    let mut puzzle = String::new();
-    f.read_to_string(&mut puzzle).expect("Could not read input");
-
-    let connected = connected_nodes(&puzzle, 0);
-    let disconnected = disconnected_graphs(&puzzle);
-    println!("Puzzle #1: {}", connected.len());
-    println!("Puzzle #2: {}", disconnected.len());
+    let puzzle = input::load_or_exit(12, env::args().nth(1).as_deref());
+    let graph = solver::or_exit(Day12.parse(&puzzle));
+    solver::print_answers(&Day12, &graph);
 }
-
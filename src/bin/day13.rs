@@ -1,23 +1,13 @@
 extern crate aoc17;
 
-use std::fs::File;
-use std::io::prelude::*;
+use std::env;
 
-use aoc17::day13::*;
+use aoc17::input;
+use aoc17::day13::Day13;
+use aoc17::solver::{self, Solver};
 
 fn main() {
-    let mut f = File::open("input/day13.txt").expect("Could not open input");
-    let mut puzzle = String::new();
-    f.read_to_string(&mut puzzle).expect("Could not read input");
-
-    let firewall = create_firewall(&puzzle);
-
-    println!("Puzzle #1: {}", severity(&firewall, 0));
-
-    let mut delay = 0;
-    while !transmitted(&firewall, delay) {
-        delay += 1;
-    }
-    println!("Puzzle #2: {}", delay);
+    let puzzle = input::load_or_exit(13, env::args().nth(1).as_deref());
+    let firewall = solver::or_exit(Day13.parse(&puzzle));
+    solver::print_answers(&Day13, &firewall);
 }
-
@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day14::Day14;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(14, env::args().nth(1).as_deref());
+    let disk = solver::or_exit(Day14.parse(&puzzle));
+    solver::print_answers(&Day14, &disk);
+}
@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day15::Day15;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(15, env::args().nth(1).as_deref());
+    let starts = solver::or_exit(Day15.parse(&puzzle));
+    solver::print_answers(&Day15, &starts);
+}
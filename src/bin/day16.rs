@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day16::Day16;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(16, env::args().nth(1).as_deref());
+    let moves = solver::or_exit(Day16.parse(&puzzle));
+    solver::print_answers(&Day16, &moves);
+}
@@ -0,0 +1,19 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day17::{Day17, Spinlock, INSERTIONS1};
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(17, env::args().nth(1).as_deref());
+    let steps = solver::or_exit(Day17.parse(&puzzle));
+
+    let mut spinlock = Spinlock::new(steps);
+    for _ in 0..INSERTIONS1 {
+        spinlock.insert();
+    }
+    println!("{}", spinlock.around(spinlock.position(), 3));
+    solver::print_answers(&Day17, &steps);
+}
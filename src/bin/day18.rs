@@ -0,0 +1,20 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day18::{duet, Day18};
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(18, env::args().nth(1).as_deref());
+    let instructions = solver::or_exit(Day18.parse(&puzzle));
+
+    // A duet which fails is reported by the answer to puzzle #2
+    if let Ok(outcome) = duet(&instructions) {
+        let ending = if outcome.deadlock { "deadlock" } else { "a halt" };
+        println!("Program 0 sent {} values, program 1 sent {} values, ending in {}",
+                 outcome.sent[0], outcome.sent[1], ending);
+    }
+    solver::print_answers(&Day18, &instructions);
+}
@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day19::Day19;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(19, env::args().nth(1).as_deref());
+    let diagram = solver::or_exit(Day19.parse(&puzzle));
+    solver::print_answers(&Day19, &diagram);
+}
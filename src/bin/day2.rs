@@ -0,0 +1,20 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day2::{divisible_pairs, row_ranges, Day2};
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(2, env::args().nth(1).as_deref());
+    let spreadsheet = solver::or_exit(Day2.parse(&puzzle));
+
+    for range in row_ranges(&spreadsheet).iter() {
+        println!("Row {}: {} - {} = {}", range.row, range.max, range.min, range.difference());
+    }
+    for pair in divisible_pairs(&spreadsheet).iter() {
+        println!("Row {}: {} / {} = {}", pair.row, pair.dividend, pair.divisor, pair.quotient());
+    }
+    solver::print_answers(&Day2, &spreadsheet);
+}
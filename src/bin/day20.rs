@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day20::Day20;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(20, env::args().nth(1).as_deref());
+    let particles = solver::or_exit(Day20.parse(&puzzle));
+    solver::print_answers(&Day20, &particles);
+}
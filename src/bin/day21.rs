@@ -0,0 +1,28 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day21::{Day21, Pattern, ITERATIONS1, START};
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    // The final pattern of part 1 is only printed if asked for with `--render`
+    let mut args : Vec<String> = env::args().skip(1).collect();
+    let render = match args.iter().position(|arg| arg == "--render") {
+        Some(idx) => {
+            args.remove(idx);
+            true
+        },
+        None => false
+    };
+
+    let puzzle = input::load_or_exit(21, args.first().map(|arg| arg.as_str()));
+    let rules = solver::or_exit(Day21.parse(&puzzle));
+
+    if render {
+        let start = Pattern::parse(START).unwrap();
+        println!("{}", solver::or_exit(rules.iterate(&start, ITERATIONS1)));
+    }
+    solver::print_answers(&Day21, &rules);
+}
@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day22::Day22;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(22, env::args().nth(1).as_deref());
+    let cluster = solver::or_exit(Day22.parse(&puzzle));
+    solver::print_answers(&Day22, &cluster);
+}
@@ -0,0 +1,21 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day18::Registers;
+use aoc17::day23::{profile, Day23};
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(23, env::args().nth(1).as_deref());
+    let instructions = solver::or_exit(Day23.parse(&puzzle));
+
+    // Show how many times each instruction runs; a failing run is reported by puzzle #1
+    if let Ok(program) = profile(&instructions, Registers::new()) {
+        for (instruction, count) in instructions.iter().zip(program.executions()) {
+            println!("{:>10}  {}", count, instruction);
+        }
+    }
+    solver::print_answers(&Day23, &instructions);
+}
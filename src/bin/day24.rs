@@ -0,0 +1,16 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day24::Day24;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(24, env::args().nth(1).as_deref());
+    let bridges = solver::or_exit(Day24.parse(&puzzle));
+
+    println!("Strongest bridge: {}", bridges.strongest);
+    println!("Longest bridge: {}", bridges.longest);
+    solver::print_answers(&Day24, &bridges);
+}
@@ -0,0 +1,13 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day25::Day25;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(25, env::args().nth(1).as_deref());
+    let blueprint = solver::or_exit(Day25.parse(&puzzle));
+    solver::print_answers(&Day25, &blueprint);
+}
@@ -1,11 +1,13 @@
 extern crate aoc17;
 
-use aoc17::day3::*;
+use std::env;
+
+use aoc17::input;
+use aoc17::day3::Day3;
+use aoc17::solver::{self, Solver};
 
 fn main() {
-    let puzzle = 265149;
-    let (x, y) = spiral_coordinates(puzzle);
-    let z = first_spiral_number_larger_than(puzzle);
-    println!("Puzzle #1: {}", x.abs() + y.abs());
-    println!("Puzzle #2: {}", z);
+    let puzzle = input::load_or_exit(3, env::args().nth(1).as_deref());
+    let num = solver::or_exit(Day3.parse(&puzzle));
+    solver::print_answers(&Day3, &num);
 }
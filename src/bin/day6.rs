@@ -1,10 +1,13 @@
 extern crate aoc17;
 
-use aoc17::day6::*;
+use std::env;
+
+use aoc17::input;
+use aoc17::day6::Day6;
+use aoc17::solver::{self, Solver};
 
 fn main() {
-    let puzzle = "0	5	10	0	11	14	13	4	11	8	8	7	1	4	12	11";
-    let (solution1, solution2) = iterations_until_cycle(puzzle);
-    println!("Puzzle #1: {}", solution1);
-    println!("Puzzle #2: {}", solution2);
+    let puzzle = input::load_or_exit(6, env::args().nth(1).as_deref());
+    let cycle = solver::or_exit(Day6.parse(&puzzle));
+    solver::print_answers(&Day6, &cycle);
 }
@@ -0,0 +1,18 @@
+extern crate aoc17;
+
+use std::env;
+
+use aoc17::input;
+use aoc17::day7::Day7;
+use aoc17::solver::{self, Solver};
+
+fn main() {
+    let puzzle = input::load_or_exit(7, env::args().nth(1).as_deref());
+    let tower = solver::or_exit(Day7.parse(&puzzle));
+
+    if let Some(correction) = tower.balance() {
+        println!("Change {} from {} to {}", tower.name(correction.program),
+                 tower.weight(correction.program), correction.weight);
+    }
+    solver::print_answers(&Day7, &tower);
+}
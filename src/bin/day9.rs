@@ -1,17 +1,13 @@
 extern crate aoc17;
 
-use std::fs::File;
-use std::io::prelude::*;
+use std::env;
 
-use aoc17::day9::*;
+use aoc17::input;
+use aoc17::day9::Day9;
+use aoc17::solver::{self, Solver};
 
 fn main() {
-    let mut f = File::open("input/day9.txt").expect("Could not open input");
-    let mut puzzle = String::new();
-    f.read_to_string(&mut puzzle).expect("Could not read input");
-
-    let (solution1, solution2) = stream_score(&puzzle);
-    println!("Puzzle #1: {}", solution1);
-    println!("Puzzle #2: {}", solution2);
+    let puzzle = input::load_or_exit(9, env::args().nth(1).as_deref());
+    let stream = solver::or_exit(Day9.parse(&puzzle));
+    solver::print_answers(&Day9, &stream);
 }
-
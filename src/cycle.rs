@@ -0,0 +1,179 @@
+//! Utilities for finding cycles in sequences of states, where each state is computed from the one
+//! before it.
+//!
+//! Every sequence generated from a finite number of states eventually repeats. The functions here
+//! describe the repeat by the number of steps taken before the first state which is later repeated,
+//! the prefix, and the number of steps between repeats, the cycle length.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Return the length of the prefix and of the cycle of the states generated from `initial` by
+/// repeatedly calling `step` on the previous state.
+///
+/// Every state seen is remembered, so `step` is called as few times as possible, but memory grows
+/// with the number of states before the first repeat. See `brent` and `floyd` for detectors that
+/// use constant memory.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::cycle::detect;
+///
+/// // 1, 2, 4, 8, 5, 10, 9, 7, 3, 6, 1, ... modulo 11
+/// assert_eq!(detect(1, |x| 2*x % 11), (0, 10));
+/// // 0, 1, 2, 3, 4, 2, ...
+/// assert_eq!(detect(0, |&x| if x < 4 { x + 1 } else { 2 }), (2, 3));
+///
+/// // `step` is called once for each state before the first repeat
+/// let mut calls = 0;
+/// assert_eq!(detect(0, |&x| { calls += 1; if x < 4 { x + 1 } else { 2 } }), (2, 3));
+/// assert_eq!(calls, 5);
+/// ```
+pub fn detect<S, F>(initial : S, mut step : F) -> (usize, usize)
+        where S : Hash + Eq + Clone, F : FnMut(&S) -> S {
+    // Map each state seen to the step at which it was first seen
+    let mut seen = HashMap::new();
+    let mut state = initial;
+    let mut nsteps = 0;
+    loop {
+        // Look the state up before stepping from it, as the step is not needed once it repeats
+        if let Some(&first) = seen.get(&state) {
+            return (first, nsteps - first);
+        }
+        let next = step(&state);
+        seen.insert(state, nsteps);
+        state = next;
+        nsteps += 1;
+    }
+}
+
+/// Return the length of the prefix and of the cycle of the states generated from `initial`, as
+/// `detect`, using Brent's algorithm.
+///
+/// Only a few states are kept at a time, but `step` is called roughly twice as often as by
+/// `detect`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::cycle::brent;
+///
+/// assert_eq!(brent(1, |x| 2*x % 11), (0, 10));
+/// assert_eq!(brent(0, |&x| if x < 4 { x + 1 } else { 2 }), (2, 3));
+/// assert_eq!(brent(7, |&x| x), (0, 1));
+/// ```
+pub fn brent<S, F>(initial : S, mut step : F) -> (usize, usize)
+        where S : Eq + Clone, F : FnMut(&S) -> S {
+    // Find the cycle length by moving the hare until it meets the tortoise, which jumps to the hare
+    // whenever the hare has taken a power of two steps since the last jump
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // With the hare a cycle length ahead, the two first meet at the start of the cycle
+    let mut tortoise = initial.clone();
+    let mut hare = initial;
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    (prefix, length)
+}
+
+/// Return the length of the prefix and of the cycle of the states generated from `initial`, as
+/// `detect`, using Floyd's algorithm.
+///
+/// Only a few states are kept at a time, but `step` is called roughly three times as often as by
+/// `detect`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::cycle::floyd;
+///
+/// assert_eq!(floyd(1, |x| 2*x % 11), (0, 10));
+/// assert_eq!(floyd(0, |&x| if x < 4 { x + 1 } else { 2 }), (2, 3));
+/// assert_eq!(floyd(7, |&x| x), (0, 1));
+/// ```
+pub fn floyd<S, F>(initial : S, mut step : F) -> (usize, usize)
+        where S : Eq + Clone, F : FnMut(&S) -> S {
+    // Move the hare twice as fast as the tortoise until they meet somewhere in the cycle, after a
+    // number of steps which is a multiple of the cycle length
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        let next = step(&hare);
+        hare = step(&next);
+    }
+
+    // Starting the tortoise again, the two first meet at the start of the cycle
+    let mut tortoise = initial;
+    let mut prefix = 0;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        prefix += 1;
+    }
+
+    // Move the hare around the cycle once more to measure it
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    (prefix, length)
+}
+
+/// Return the state reached from `initial` after calling `step` `n` times.
+///
+/// Every state is remembered until the first repeat, after which the state after `n` steps is
+/// looked up rather than computed, so `n` may be far larger than the number of distinct states.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::cycle::state_after;
+///
+/// assert_eq!(state_after(1, |x| 2*x % 11, 3), 8);
+/// assert_eq!(state_after(1, |x| 2*x % 11, 1_000_000_000_003), 8);
+/// assert_eq!(state_after(0, |&x| if x < 4 { x + 1 } else { 2 }, 1), 1);
+/// assert_eq!(state_after(0, |&x| if x < 4 { x + 1 } else { 2 }, 1_000_000), 4);
+/// ```
+pub fn state_after<S, F>(initial : S, mut step : F, n : usize) -> S
+        where S : Hash + Eq + Clone, F : FnMut(&S) -> S {
+    let mut seen = HashMap::new();
+    let mut states = Vec::new();
+    let mut state = initial;
+    while states.len() < n {
+        if let Some(&prefix) = seen.get(&state) {
+            // States repeat every `length` steps from the end of the prefix
+            let length = states.len() - prefix;
+            return states.swap_remove(prefix + (n - prefix) % length);
+        }
+        let next = step(&state);
+        seen.insert(state.clone(), states.len());
+        states.push(state);
+        state = next;
+    }
+    state
+}
@@ -1,5 +1,8 @@
 //! Solutions to day 1.
 
+use error::{Error, Result};
+use solver::Solver;
+
 /// Return the sum of all digits that match the following offset digit in the list.
 ///
 /// Sequences are considered as cyclical across the last element and the first.
@@ -14,17 +17,20 @@
 /// use aoc17::day1::sequence_sum;
 ///
 /// // When the match with the following digit should be considered
-/// assert_eq!(sequence_sum("1122", 1), 3);
-/// assert_eq!(sequence_sum("1111", 1), 4);
-/// assert_eq!(sequence_sum("1234", 1), 0);
-/// assert_eq!(sequence_sum("91212129", 1), 9);
+/// assert_eq!(sequence_sum("1122", 1), Ok(3));
+/// assert_eq!(sequence_sum("1111", 1), Ok(4));
+/// assert_eq!(sequence_sum("1234", 1), Ok(0));
+/// assert_eq!(sequence_sum("91212129", 1), Ok(9));
 ///
 /// // When the match with the digit after len(sequence)/2 should be considered
-/// assert_eq!(sequence_sum("1212", 2), 6);
-/// assert_eq!(sequence_sum("1221", 2), 0);
-/// assert_eq!(sequence_sum("123425", 3), 4);
-/// assert_eq!(sequence_sum("123123", 3), 12);
-/// assert_eq!(sequence_sum("12131415", 4), 4);
+/// assert_eq!(sequence_sum("1212", 2), Ok(6));
+/// assert_eq!(sequence_sum("1221", 2), Ok(0));
+/// assert_eq!(sequence_sum("123425", 3), Ok(4));
+/// assert_eq!(sequence_sum("123123", 3), Ok(12));
+/// assert_eq!(sequence_sum("12131415", 4), Ok(4));
+///
+/// // Every character must be a digit
+/// assert!(sequence_sum("12a4", 1).is_err());
 /// ```
 ///
 /// # Alternative implementation
@@ -32,25 +38,62 @@
 /// Using a map-reduce method.
 ///
 /// ```rust,ignore
-/// sequence.chars()
-///         .zip(sequence.chars().cycle().skip(offset))
-///         .map(|(a, b)| (a.to_digit(10).unwrap(), b.to_digit(10).unwrap()))
-///         .fold(0, |acc, (a, b)| if a == b { acc + a } else { acc })
+/// let digits = sequence.chars().map(|c| c.to_digit(10)).collect::<Option<Vec<u32>>>()?;
+/// digits.iter()
+///       .zip(digits.iter().cycle().skip(offset))
+///       .fold(0, |acc, (a, b)| if a == b { acc + a } else { acc })
 /// ```
 ///
-pub fn sequence_sum(sequence: &str, offset: usize) -> u32 {
+pub fn sequence_sum(sequence: &str, offset: usize) -> Result<u32> {
+    let mut digits = Vec::with_capacity(sequence.len());
+    for (idx, c) in sequence.char_indices() {
+        match c.to_digit(10) {
+            Some(digit) => digits.push(digit),
+            None => {
+                let token = &sequence[idx..idx + c.len_utf8()];
+                return Err(Error::parse(Day1::DAY, 1, sequence, token, "a digit"));
+            }
+        }
+    }
+
     let mut sum : u32 = 0;
-    // Loop over the sequence in pairs of characters, and increment the sum if the two digits
-    // match.
-    for (a, b) in sequence.chars().zip(sequence.chars().cycle().skip(offset)) {
-        // We expect the input to be well-formed (i.e. containing only integers), so don't bother
-        // pattern matching the Result and just unwrap
-        let first = a.to_digit(10).unwrap();
-        let second = b.to_digit(10).unwrap();
+    // Loop over the sequence in pairs of digits, and increment the sum if the two digits match.
+    for (first, second) in digits.iter().zip(digits.iter().cycle().skip(offset)) {
         if first == second {
             sum += first;
         }
     }
 
-    sum
+    Ok(sum)
+}
+
+/// Solver for day 1.
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY : usize = 1;
+
+    type Input = String;
+    type Answer1 = Result<u32>;
+    type Answer2 = Result<u32>;
+
+    fn parse(&self, puzzle : &str) -> Result<String> {
+        let sequence = puzzle.trim();
+        if sequence.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        if let Some((idx, c)) = sequence.char_indices().find(|&(_, c)| !c.is_ascii_digit()) {
+            let token = &sequence[idx..idx + c.len_utf8()];
+            return Err(Error::parse(Self::DAY, 1, puzzle, token, "a digit"));
+        }
+        Ok(sequence.to_string())
+    }
+
+    fn part1(&self, sequence : &String) -> Result<u32> {
+        sequence_sum(sequence, 1)
+    }
+
+    fn part2(&self, sequence : &String) -> Result<u32> {
+        sequence_sum(sequence, sequence.len()/2)
+    }
 }
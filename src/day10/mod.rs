@@ -0,0 +1,225 @@
+//! Solutions for day 10.
+//!
+//! The full knot hash is exposed through `KnotHash`, so that other days can use it as a
+//! general-purpose hash function.
+
+use std::fmt::Write;
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The number of elements in the list used by the knot hash.
+pub const LIST_SIZE : usize = 256;
+
+/// The lengths appended to the input of the full knot hash.
+const LENGTH_SUFFIX : [u8; 5] = [17, 31, 73, 47, 23];
+
+/// The number of rounds performed by the full knot hash.
+const NROUNDS : usize = 64;
+
+/// The state of a circular list being tied into knots.
+struct Knot {
+    list : Vec<u8>,
+    position : usize,
+    skip : usize
+}
+
+impl Knot {
+    /// Return a knot of the list `0, 1, ..., size - 1`.
+    fn new(size : usize) -> Knot {
+        assert!(size > 0 && size <= LIST_SIZE);
+        Knot { list: (0..size).map(|x| x as u8).collect(), position: 0, skip: 0 }
+    }
+
+    /// Perform a single round of twists, one per length.
+    fn round<I : IntoIterator<Item = usize>>(&mut self, lengths : I) {
+        let size = self.list.len();
+        for length in lengths {
+            assert!(length <= size);
+            // Reverse the sublist of `length` elements starting at `position`, wrapping around
+            for i in 0..length/2 {
+                let a = (self.position + i) % size;
+                let b = (self.position + length - 1 - i) % size;
+                self.list.swap(a, b);
+            }
+            self.position = (self.position + length + self.skip) % size;
+            self.skip += 1;
+        }
+    }
+}
+
+/// Return the list after a single round of the knot hash over a list of `size` elements.
+///
+/// The list must have between 1 and `LIST_SIZE` elements, and no length may be larger than it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day10::single_round;
+///
+/// assert_eq!(single_round(5, &[3, 4, 1, 5]), Ok(vec![3, 4, 2, 1, 0]));
+/// assert_eq!(single_round(5, &[3, 6]),
+///            Err(Error::Invalid { day: 10,
+///                                 message: "length 6 is larger than the list of 5 elements"
+///                                          .to_string() }));
+/// assert!(single_round(0, &[]).is_err());
+/// assert!(single_round(257, &[3]).is_err());
+/// ```
+pub fn single_round(size : usize, lengths : &[usize]) -> Result<Vec<u8>> {
+    let invalid = |message| Error::Invalid { day: Day10::DAY, message };
+    if size == 0 || size > LIST_SIZE {
+        return Err(invalid(format!("a list of {} elements is not between 1 and {} elements long",
+                                   size, LIST_SIZE)));
+    }
+    if let Some(&length) = lengths.iter().find(|&&length| length > size) {
+        return Err(invalid(format!("length {} is larger than the list of {} elements",
+                                   length, size)));
+    }
+    let mut knot = Knot::new(size);
+    knot.round(lengths.iter().cloned());
+    Ok(knot.list)
+}
+
+/// Return `bytes` formatted as lowercase hexadecimal.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day10::to_hex;
+///
+/// assert_eq!(to_hex(&[64, 7, 255]), "4007ff");
+/// ```
+pub fn to_hex(bytes : &[u8]) -> String {
+    let mut hex = String::with_capacity(2*bytes.len());
+    for b in bytes {
+        write!(hex, "{:02x}", b).unwrap();
+    }
+    hex
+}
+
+/// The full knot hash of a sequence of bytes.
+///
+/// The knot hash twists the list once per input byte in each of its 64 rounds, so the input is
+/// buffered by `update` and the rounds are only run by `finalize`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day10::KnotHash;
+///
+/// assert_eq!(KnotHash::new().hex(), "a2582a3a0e66e6e86e3812dcb672a272");
+///
+/// let mut hash = KnotHash::new();
+/// hash.update(b"AoC ").update(b"2017");
+/// assert_eq!(hash.hex(), "33efeb34ea91902bb2f59c9920caa6cd");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct KnotHash {
+    input : Vec<u8>
+}
+
+impl KnotHash {
+    /// Return a hash of no input.
+    pub fn new() -> KnotHash {
+        KnotHash::default()
+    }
+
+    /// Append `data` to the input being hashed.
+    pub fn update(&mut self, data : &[u8]) -> &mut KnotHash {
+        self.input.extend_from_slice(data);
+        self
+    }
+
+    /// Return the dense hash of the input.
+    pub fn finalize(&self) -> [u8; 16] {
+        let lengths : Vec<usize> = self.input.iter().chain(LENGTH_SUFFIX.iter())
+                                       .map(|&b| b as usize).collect();
+        let mut knot = Knot::new(LIST_SIZE);
+        for _ in 0..NROUNDS {
+            knot.round(lengths.iter().cloned());
+        }
+
+        // Reduce the sparse hash to the dense hash by XORing each block of 16 elements
+        let mut dense = [0; 16];
+        for (d, block) in dense.iter_mut().zip(knot.list.chunks(16)) {
+            *d = block.iter().fold(0, |acc, x| acc ^ x);
+        }
+        dense
+    }
+
+    /// Return the dense hash of the input as lowercase hexadecimal.
+    pub fn hex(&self) -> String {
+        to_hex(&self.finalize())
+    }
+}
+
+/// Return the knot hash of `data`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day10::{knot_hash, to_hex};
+///
+/// assert_eq!(to_hex(&knot_hash(b"1,2,3")), "3efbe78a8d82f29979031a4aa0b16a9d");
+/// assert_eq!(to_hex(&knot_hash(b"1,2,4")), "63960835bcdc130f0b66d7ff4f6a5a8e");
+/// ```
+pub fn knot_hash(data : &[u8]) -> [u8; 16] {
+    KnotHash::new().update(data).finalize()
+}
+
+/// The puzzle input, read both as a list of lengths and as a string of bytes.
+pub struct Input {
+    lengths : Vec<usize>,
+    bytes : Vec<u8>
+}
+
+/// Return the comma-separated list of lengths in `line`.
+///
+/// Each length must be no larger than `LIST_SIZE`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day10::parse_lengths;
+///
+/// assert_eq!(parse_lengths("3, 4,1,5"), Ok(vec![3, 4, 1, 5]));
+/// assert!(parse_lengths("3,4,257").is_err());
+/// ```
+pub fn parse_lengths(line : &str) -> Result<Vec<usize>> {
+    line.split(',').map(|token| {
+        let token = token.trim();
+        match token.parse::<usize>() {
+            Ok(length) if length <= LIST_SIZE => Ok(length),
+            _ => Err(Error::parse(Day10::DAY, 1, line, token, "a length no larger than 256"))
+        }
+    }).collect()
+}
+
+/// Solver for day 10.
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY : usize = 10;
+
+    type Input = Input;
+    type Answer1 = Result<usize>;
+    type Answer2 = String;
+
+    fn parse(&self, puzzle : &str) -> Result<Input> {
+        let line = puzzle.trim();
+        if line.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        Ok(Input { lengths: parse_lengths(line)?, bytes: line.as_bytes().to_vec() })
+    }
+
+    fn part1(&self, input : &Input) -> Result<usize> {
+        let list = single_round(LIST_SIZE, &input.lengths)?;
+        Ok((list[0] as usize)*(list[1] as usize))
+    }
+
+    fn part2(&self, input : &Input) -> String {
+        to_hex(&knot_hash(&input.bytes))
+    }
+}
@@ -0,0 +1,164 @@
+//! Solutions for day 11.
+
+use std::ops::Add;
+
+use error::{Error, Result};
+use grid::{self, GridCoordinate};
+use solver::Solver;
+
+/// A position on a hexagonal grid whose hexagons have flat tops and bottoms, in axial form.
+///
+/// The third cube coordinate is `s = -q - r`, so that `q + r + s = 0` for every hexagon. Moving
+/// north increases `r` and decreases `s`, and moving north-east increases `q` and decreases `s`.
+#[derive(Debug, Hash, PartialEq, Eq, Copy, Clone)]
+pub struct HexCoordinate {
+    pub q : isize,
+    pub r : isize
+}
+
+impl HexCoordinate {
+    /// Return the third cube coordinate of the hexagon.
+    pub fn s(&self) -> isize {
+        -self.q - self.r
+    }
+}
+
+impl Add for HexCoordinate {
+    type Output = HexCoordinate;
+
+    fn add(self, other : HexCoordinate) -> HexCoordinate {
+        HexCoordinate { q: self.q + other.q, r: self.r + other.r }
+    }
+}
+
+impl GridCoordinate for HexCoordinate {
+    fn origin() -> HexCoordinate {
+        HexCoordinate { q: 0, r: 0 }
+    }
+
+    fn unit_steps() -> Vec<HexCoordinate> {
+        Direction::ALL.iter().map(|d| d.step()).collect()
+    }
+
+    fn distance(&self, other : &HexCoordinate) -> usize {
+        let dq = (self.q - other.q).abs();
+        let dr = (self.r - other.r).abs();
+        let ds = (self.s() - other.s()).abs();
+        dq.max(dr).max(ds) as usize
+    }
+}
+
+/// A step from a hexagon to one of its six neighbours.
+#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+pub enum Direction {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL : [Direction; 6] = [
+        Direction::North,
+        Direction::NorthEast,
+        Direction::SouthEast,
+        Direction::South,
+        Direction::SouthWest,
+        Direction::NorthWest
+    ];
+
+    /// Return the offset of the neighbour in this direction.
+    pub fn step(&self) -> HexCoordinate {
+        let (q, r) = match *self {
+            Direction::North => (0, 1),
+            Direction::NorthEast => (1, 0),
+            Direction::SouthEast => (1, -1),
+            Direction::South => (0, -1),
+            Direction::SouthWest => (-1, 0),
+            Direction::NorthWest => (-1, 1)
+        };
+        HexCoordinate { q, r }
+    }
+
+    /// Return the direction named by `token`, one of `n`, `ne`, `se`, `s`, `sw` or `nw`.
+    fn from_token(token : &str) -> Option<Direction> {
+        match token {
+            "n" => Some(Direction::North),
+            "ne" => Some(Direction::NorthEast),
+            "se" => Some(Direction::SouthEast),
+            "s" => Some(Direction::South),
+            "sw" => Some(Direction::SouthWest),
+            "nw" => Some(Direction::NorthWest),
+            _ => None
+        }
+    }
+}
+
+/// Return the comma-separated list of steps in `line`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day11::{parse_steps, Direction};
+///
+/// assert_eq!(parse_steps("ne, s,nw"),
+///            Ok(vec![Direction::NorthEast, Direction::South, Direction::NorthWest]));
+/// assert!(parse_steps("ne,e").is_err());
+/// ```
+pub fn parse_steps(line : &str) -> Result<Vec<Direction>> {
+    line.split(',').map(|token| {
+        let token = token.trim();
+        Direction::from_token(token).ok_or_else(|| {
+            Error::parse(Day11::DAY, 1, line, token, "one of `n`, `ne`, `se`, `s`, `sw` or `nw`")
+        })
+    }).collect()
+}
+
+/// Return the distance from the origin to the end of `steps`, and the furthest distance from the
+/// origin reached along the way.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day11::{parse_steps, distances};
+///
+/// assert_eq!(distances(&parse_steps("ne,ne,ne").unwrap()), (3, 3));
+/// assert_eq!(distances(&parse_steps("ne,ne,sw,sw").unwrap()), (0, 2));
+/// assert_eq!(distances(&parse_steps("ne,ne,s,s").unwrap()), (2, 2));
+/// assert_eq!(distances(&parse_steps("se,sw,se,sw,sw").unwrap()), (3, 3));
+/// ```
+pub fn distances(steps : &[Direction]) -> (usize, usize) {
+    let origin = HexCoordinate::origin();
+    let (end, furthest) = grid::furthest_distance(origin, steps.iter().map(|d| d.step()));
+    (origin.distance(&end), furthest)
+}
+
+/// Solver for day 11.
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY : usize = 11;
+
+    type Input = Vec<Direction>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<Direction>> {
+        let line = puzzle.trim();
+        if line.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        parse_steps(line)
+    }
+
+    fn part1(&self, steps : &Vec<Direction>) -> usize {
+        distances(steps).0
+    }
+
+    fn part2(&self, steps : &Vec<Direction>) -> usize {
+        distances(steps).1
+    }
+}
@@ -2,7 +2,11 @@
 
 use std::collections::HashSet;
 
-struct Graph {
+use error::{Error, Result};
+use solver::Solver;
+
+/// An undirected graph, stored as the list of nodes adjacent to each node.
+pub struct Graph {
     nodes : Vec<Vec<usize>>
 }
 
@@ -11,20 +15,71 @@ impl Graph {
     ///
     /// The graph storage member will expand dynamically if more nodes are added than `size_hint`
     /// suggests.
-    fn new(size_hint : usize) -> Graph {
+    pub fn new(size_hint : usize) -> Graph {
         let nodes = Vec::with_capacity(size_hint);
         Graph { nodes }
     }
 
+    /// Return a graph of the cells of `grid` that are set, each connected to the set cells directly
+    /// above, below, left and right of it.
+    ///
+    /// Nodes are numbered in row-major order, counting only the cells that are set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day12::Graph;
+    ///
+    /// let graph = Graph::from_grid(&[vec![true, true, false],
+    ///                                vec![false, false, true]]);
+    /// assert_eq!(graph.connected_nodes(0), vec![0, 1]);
+    /// assert_eq!(graph.disconnected_graphs().len(), 2);
+    /// ```
+    pub fn from_grid(grid : &[Vec<bool>]) -> Graph {
+        // Number each set cell, so that neighbours can be looked up by position
+        let mut numbers = Vec::with_capacity(grid.len());
+        let mut nnodes = 0;
+        for row in grid.iter() {
+            numbers.push(row.iter().map(|&set| {
+                if set {
+                    nnodes += 1;
+                    Some(nnodes - 1)
+                } else {
+                    None
+                }
+            }).collect::<Vec<_>>());
+        }
+
+        let number = |y : usize, x : usize| {
+            numbers.get(y).and_then(|row| row.get(x)).cloned().and_then(|n| n)
+        };
+        let mut graph = Graph::new(nnodes);
+        for (y, row) in numbers.iter().enumerate() {
+            for (x, node) in row.iter().enumerate() {
+                if let Some(node) = *node {
+                    let mut vertices = vec![number(y + 1, x), number(y, x + 1)];
+                    if y > 0 {
+                        vertices.push(number(y - 1, x));
+                    }
+                    if x > 0 {
+                        vertices.push(number(y, x - 1));
+                    }
+                    graph.add_node(node, vertices.into_iter().flatten().collect());
+                }
+            }
+        }
+        graph
+    }
+
     /// Add a node to the graph, connected to `vertices`.
-    fn add_node(&mut self, node : usize, vertices : Vec<usize>) {
+    pub fn add_node(&mut self, node : usize, vertices : Vec<usize>) {
         self.nodes.insert(node, vertices);
     }
 
     /// Return the list of nodes connected to `node`.
     ///
     /// Implements a non-recursive depth-first search algorithm.
-    fn connected_nodes(&self, node : usize) -> Vec<usize> {
+    pub fn connected_nodes(&self, node : usize) -> Vec<usize> {
         // let mut ret = Vec::with_capacity(self.nodes.len());
 
         // Records whether a node has been visited
@@ -59,7 +114,7 @@ impl Graph {
     }
 
     /// Return a vector of vectors of nodes representing all the disconnected subgraphs.
-    fn disconnected_graphs(&self) -> Vec<Vec<usize>> {
+    pub fn disconnected_graphs(&self) -> Vec<Vec<usize>> {
         let mut graphs = vec![];
 
         let mut known_nodes : HashSet<usize> = HashSet::new();
@@ -78,20 +133,45 @@ impl Graph {
 }
 
 /// Return a `Graph` object made from the `nodes` string.
-fn construct_graph(nodes : &str) -> Graph {
-    let mut graph = Graph::new(nodes.lines().count());
-
-    for line in nodes.lines() {
-        let mut components = line.split(" <-> ");
-        let node : usize = components.next().unwrap().parse().unwrap();
-        let connected : Vec<_> = components.next().unwrap()
-                                            .split(", ")
-                                            .map(|x| x.parse().unwrap())
-                                            .collect();
-        graph.add_node(node, connected);
+///
+/// Nodes must be listed in order, starting from zero, and may only be connected to listed nodes.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day12::construct_graph;
+///
+/// assert!(construct_graph("0 <-> 1\n1 <-> 0").is_ok());
+/// assert_eq!(construct_graph("0 <-> 1\n1 <-> 2").err(),
+///            Some(Error::Parse { day: 12, line: 2, column: 7, token: "2".to_string(),
+///                                expected: "a listed node" }));
+/// ```
+pub fn construct_graph(nodes : &str) -> Result<Graph> {
+    let nnodes = nodes.lines().count();
+    if nnodes == 0 {
+        return Err(Error::EmptyInput { day: Day12::DAY });
+    }
+    let mut graph = Graph::new(nnodes);
+
+    for (idx, line) in nodes.lines().enumerate() {
+        let error = |token : &str, expected| Error::parse(Day12::DAY, idx + 1, line, token, expected);
+        let mut components = line.splitn(2, " <-> ");
+        let node = components.next().unwrap_or("");
+        if node.parse() != Ok(idx) {
+            return Err(error(node, "the next node in order"));
+        }
+        let mut connected = Vec::new();
+        for x in components.next().unwrap_or("").split(", ") {
+            match x.parse() {
+                Ok(n) if n < nnodes => connected.push(n),
+                _ => return Err(error(x, "a listed node"))
+            }
+        }
+        graph.add_node(idx, connected);
     }
 
-    graph
+    Ok(graph)
 }
 
 /// Return a sorted list of nodes that are connected to node `n`.
@@ -107,11 +187,11 @@ fn construct_graph(nodes : &str) -> Graph {
 /// 3 <-> 2, 4
 /// 4 <-> 2, 3, 6
 /// 5 <-> 6
-/// 6 <-> 4, 5", 0), vec![0, 2, 3, 4, 5, 6]);
+/// 6 <-> 4, 5", 0), Ok(vec![0, 2, 3, 4, 5, 6]));
 /// ```
-pub fn connected_nodes(nodes : &str, node : usize) -> Vec<usize> {
-    let graph = construct_graph(nodes);
-    graph.connected_nodes(node)
+pub fn connected_nodes(nodes : &str, node : usize) -> Result<Vec<usize>> {
+    let graph = construct_graph(nodes)?;
+    Ok(graph.connected_nodes(node))
 }
 
 /// Return a list of lists of nodes that are subgraphs of `nodes`.
@@ -127,9 +207,32 @@ pub fn connected_nodes(nodes : &str, node : usize) -> Vec<usize> {
 /// 3 <-> 2, 4
 /// 4 <-> 2, 3, 6
 /// 5 <-> 6
-/// 6 <-> 4, 5"), vec![vec![0, 2, 3, 4, 5, 6], vec![1]]);
+/// 6 <-> 4, 5"), Ok(vec![vec![0, 2, 3, 4, 5, 6], vec![1]]));
 /// ```
-pub fn disconnected_graphs(nodes : &str) -> Vec<Vec<usize>> {
-    let graph = construct_graph(nodes);
-    graph.disconnected_graphs()
+pub fn disconnected_graphs(nodes : &str) -> Result<Vec<Vec<usize>>> {
+    let graph = construct_graph(nodes)?;
+    Ok(graph.disconnected_graphs())
+}
+
+/// Solver for day 12.
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY : usize = 12;
+
+    type Input = Graph;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Graph> {
+        construct_graph(puzzle)
+    }
+
+    fn part1(&self, graph : &Graph) -> usize {
+        graph.connected_nodes(0).len()
+    }
+
+    fn part2(&self, graph : &Graph) -> usize {
+        graph.disconnected_graphs().len()
+    }
 }
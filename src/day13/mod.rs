@@ -1,29 +1,52 @@
 //! Solutions for day 13.
 
+use error::{Error, Result};
+use solver::Solver;
+
 /// Return a `Vec` representation of the `firewall`.
 ///
 /// # Examples
 ///
 /// ```
+/// use aoc17::Error;
 /// use aoc17::day13::create_firewall;
 ///
 /// assert_eq!(create_firewall("0: 3
 /// 1: 2
 /// 4: 4
-/// 6: 4"), vec![(0, 3), (1, 2), (4, 4), (6, 4)]);
+/// 6: 4"), Ok(vec![(0, 3), (1, 2), (4, 4), (6, 4)]));
+///
+/// assert!(create_firewall("0: 3\n1: 0").is_err());
+/// assert_eq!(create_firewall(""), Err(Error::EmptyInput { day: 13 }));
 /// ```
-pub fn create_firewall(firewall : &str) -> Vec<(usize, usize)> {
+pub fn create_firewall(firewall : &str) -> Result<Vec<(usize, usize)>> {
     let mut ret = Vec::new();
-    for line in firewall.lines() {
-        let mut row : Vec<_> = line.split(": ")
-                                   .map(|x| x.parse::<usize>().unwrap())
-                                   .collect();
-        let length = row.pop().unwrap();
-        let idx = row.pop().unwrap();
+    for (lineno, line) in firewall.lines().enumerate() {
+        let lineno = lineno + 1;
+        let mut row = line.splitn(2, ": ");
+        let idx = row.next().unwrap_or("");
+        let length = row.next().unwrap_or("");
+        let idx = idx.parse::<usize>()
+                     .map_err(|_| Error::parse(Day13::DAY, lineno, line, idx, "a layer depth"))?;
+        let length = match length.parse::<usize>() {
+            Ok(length) if length > 0 => length,
+            _ => return Err(Error::parse(Day13::DAY, lineno, line, length, "a layer range"))
+        };
         ret.push((idx, length));
     }
+    if ret.is_empty() {
+        return Err(Error::EmptyInput { day: Day13::DAY });
+    }
 
-    ret
+    Ok(ret)
+}
+
+/// Return true if the layer at depth `idx` with the given range catches a packet which started
+/// after a `delay` time units.
+///
+/// A layer of range 1 never moves, so it catches every packet.
+fn caught(idx : usize, length : usize, delay : usize) -> bool {
+    length == 1 || (idx + delay) % (2*(length - 1)) == 0
 }
 
 /// Return the severity score of packet transmission for the given `firewall`.
@@ -38,16 +61,19 @@ pub fn create_firewall(firewall : &str) -> Vec<(usize, usize)> {
 /// assert_eq!(severity(&create_firewall("0: 3
 /// 1: 2
 /// 4: 4
-/// 6: 4"), 0), 24);
+/// 6: 4").unwrap(), 0), 24);
 ///
 /// assert_eq!(severity(&create_firewall("0: 3
 /// 1: 2
 /// 4: 4
-/// 6: 4"), 10), 0);
+/// 6: 4").unwrap(), 10), 0);
+///
+/// assert_eq!(severity(&create_firewall("0: 3\n2: 1").unwrap(), 1), 2);
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn severity(firewall : &Vec<(usize, usize)>, delay : usize) -> usize {
     firewall.iter().map(|&(idx, length)| {
-        if ((idx + delay) % (2*(length - 1))) == 0 {
+        if caught(idx, length, delay) {
             idx*length
         } else {
             0
@@ -67,15 +93,70 @@ pub fn severity(firewall : &Vec<(usize, usize)>, delay : usize) -> usize {
 /// assert_eq!(transmitted(&create_firewall("0: 3
 /// 1: 2
 /// 4: 4
-/// 6: 4"), 0), false);
+/// 6: 4").unwrap(), 0), false);
 ///
 /// assert_eq!(transmitted(&create_firewall("0: 3
 /// 1: 2
 /// 4: 4
-/// 6: 4"), 10), true);
+/// 6: 4").unwrap(), 10), true);
+///
+/// assert_eq!(transmitted(&create_firewall("0: 3\n2: 1").unwrap(), 1), false);
 /// ```
+#[allow(clippy::ptr_arg)]
 pub fn transmitted(firewall : &Vec<(usize, usize)>, delay : usize) -> bool {
-    !firewall.iter().any(|&(idx, length)| {
-        (idx + delay) % (2*(length - 1)) == 0
-    })
+    !firewall.iter().any(|&(idx, length)| caught(idx, length, delay))
+}
+
+/// Return the smallest delay for which a packet transmission passes the given `firewall`.
+///
+/// Returns `None` if the firewall has a layer of range 1, which catches every packet.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day13::{create_firewall, minimum_delay};
+///
+/// assert_eq!(minimum_delay(&create_firewall("0: 3
+/// 1: 2
+/// 4: 4
+/// 6: 4").unwrap()), Some(10));
+///
+/// assert_eq!(minimum_delay(&create_firewall("0: 3\n2: 1").unwrap()), None);
+/// ```
+pub fn minimum_delay(firewall : &Vec<(usize, usize)>) -> Option<usize> {
+    if firewall.iter().any(|&(_, length)| length == 1) {
+        return None;
+    }
+    let mut delay = 0;
+    while !transmitted(firewall, delay) {
+        delay += 1;
+    }
+
+    Some(delay)
+}
+
+/// Solver for day 13.
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY : usize = 13;
+
+    type Input = Vec<(usize, usize)>;
+    type Answer1 = usize;
+    type Answer2 = Result<usize>;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<(usize, usize)>> {
+        create_firewall(puzzle)
+    }
+
+    fn part1(&self, firewall : &Vec<(usize, usize)>) -> usize {
+        severity(firewall, 0)
+    }
+
+    fn part2(&self, firewall : &Vec<(usize, usize)>) -> Result<usize> {
+        minimum_delay(firewall).ok_or_else(|| Error::Invalid {
+            day: Self::DAY,
+            message: "a layer of range 1 catches the packet whatever the delay".to_string()
+        })
+    }
 }
@@ -0,0 +1,101 @@
+//! Solutions for day 14.
+
+use day10::knot_hash;
+use day12::Graph;
+use error::{Error, Result};
+use solver::Solver;
+
+/// The number of rows and columns of squares on the disk.
+pub const DISK_SIZE : usize = 128;
+
+/// The squares of a disk, each either used or free.
+///
+/// Each row is stored as the 128 bits of the knot hash of `<key>-<row>`, with the leftmost square
+/// in the most significant bit.
+pub struct Disk {
+    rows : Vec<u128>
+}
+
+impl Disk {
+    /// Return the disk generated from the key string `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day14::Disk;
+    ///
+    /// let disk = Disk::new("flqrgnkx");
+    /// assert!(disk.is_used(0, 0));
+    /// assert!(!disk.is_used(0, 2));
+    /// assert!(disk.is_used(1, 1));
+    /// ```
+    pub fn new(key : &str) -> Disk {
+        let rows = (0..DISK_SIZE).map(|row| {
+            let hash = knot_hash(format!("{}-{}", key, row).as_bytes());
+            hash.iter().fold(0, |acc, &b| (acc << 8) | b as u128)
+        }).collect();
+        Disk { rows }
+    }
+
+    /// Return true if the square in `row` and `column` is used.
+    pub fn is_used(&self, row : usize, column : usize) -> bool {
+        self.rows[row] & (1 << (DISK_SIZE - 1 - column)) != 0
+    }
+
+    /// Return the number of used squares.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day14::Disk;
+    ///
+    /// assert_eq!(Disk::new("flqrgnkx").used_squares(), 8108);
+    /// ```
+    pub fn used_squares(&self) -> usize {
+        self.rows.iter().map(|row| row.count_ones() as usize).sum()
+    }
+
+    /// Return the number of regions of used squares, where squares in a region are connected
+    /// horizontally or vertically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day14::Disk;
+    ///
+    /// assert_eq!(Disk::new("flqrgnkx").regions(), 1242);
+    /// ```
+    pub fn regions(&self) -> usize {
+        let grid : Vec<Vec<bool>> = (0..DISK_SIZE).map(|row| {
+            (0..DISK_SIZE).map(|column| self.is_used(row, column)).collect()
+        }).collect();
+        Graph::from_grid(&grid).disconnected_graphs().len()
+    }
+}
+
+/// Solver for day 14.
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY : usize = 14;
+
+    type Input = Disk;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Disk> {
+        let key = puzzle.trim();
+        if key.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        Ok(Disk::new(key))
+    }
+
+    fn part1(&self, disk : &Disk) -> usize {
+        disk.used_squares()
+    }
+
+    fn part2(&self, disk : &Disk) -> usize {
+        disk.regions()
+    }
+}
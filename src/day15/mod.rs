@@ -0,0 +1,234 @@
+//! Solutions for day 15.
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The factor used by generator A.
+pub const FACTOR_A : u64 = 16807;
+
+/// The factor used by generator B.
+pub const FACTOR_B : u64 = 48271;
+
+/// The modulus used by both generators, which is the Mersenne prime 2^31 - 1.
+pub const MODULUS : u64 = 0x7fff_ffff;
+
+/// The number of pairs compared by the judge in part 1.
+pub const PAIRS1 : usize = 40_000_000;
+
+/// The number of pairs compared by the judge in part 2.
+pub const PAIRS2 : usize = 5_000_000;
+
+/// A generator of values, each the previous value multiplied by a factor modulo a modulus.
+///
+/// Only values which are multiples of `multiple` are yielded. The values eventually repeat, so
+/// the generator ends once it has gone round a cycle of values none of which are multiples.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day15::{Generator, FACTOR_A, MODULUS};
+///
+/// let values : Vec<u64> = Generator::new(65, FACTOR_A).take(3).collect();
+/// assert_eq!(values, vec![1092455, 1181022009, 245556042]);
+///
+/// let values : Vec<u64> = Generator::new(65, FACTOR_A).with_multiple(4).take(2).collect();
+/// assert_eq!(values, vec![1352636452, 1992081072]);
+///
+/// let values : Vec<u64> = Generator::new(1, 3).with_modulus(7).take(6).collect();
+/// assert_eq!(values, vec![3, 2, 6, 4, 5, 1]);
+///
+/// // No power of 3 modulo 7 is a multiple of 8, and 2 and 4 repeat without reaching 3
+/// assert_eq!(Generator::new(1, 3).with_modulus(7).with_multiple(8).next(), None);
+/// let values : Vec<u64> = Generator::new(1, 2).with_modulus(6).with_multiple(3).collect();
+/// assert_eq!(values, vec![]);
+/// let values : Vec<u64> = Generator::new(1, 2).with_modulus(6).with_multiple(4).take(3).collect();
+/// assert_eq!(values, vec![4, 4, 4]);
+///
+/// // Starts and factors are only meaningful modulo the modulus
+/// let values : Vec<u64> = Generator::new(65 + MODULUS, FACTOR_A + 3*MODULUS).take(3).collect();
+/// assert_eq!(values, vec![1092455, 1181022009, 245556042]);
+/// let values : Vec<u64> = Generator::new(u64::MAX, u64::MAX).with_modulus(7).take(2).collect();
+/// assert_eq!(values, vec![1, 1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Generator {
+    value : u64,
+    factor : u64,
+    modulus : u64,
+    multiple : u64
+}
+
+impl Generator {
+    /// Return a generator starting from `start` with the `factor`, which uses `MODULUS` and
+    /// yields every value.
+    pub fn new(start : u64, factor : u64) -> Generator {
+        Generator { value: start, factor, modulus: MODULUS, multiple: 1 }
+    }
+
+    /// Return the generator using `modulus` instead, which must not be zero.
+    pub fn with_modulus(self, modulus : u64) -> Generator {
+        assert!(modulus > 0);
+        Generator { modulus, ..self }
+    }
+
+    /// Return the generator only yielding the values which are multiples of `multiple`.
+    pub fn with_multiple(self, multiple : u64) -> Generator {
+        assert!(multiple > 0);
+        Generator { multiple, ..self }
+    }
+
+    /// Advance to the next value, whether or not it is a multiple.
+    fn step(&mut self) {
+        // Both the value and factor fit in 31 bits exactly when neither exceeds the modulus
+        self.value = if self.modulus == MODULUS && (self.value | self.factor) <= MODULUS {
+            // Reduce modulo a Mersenne prime by adding the high bits to the low bits, which is
+            // much faster than dividing. The product is below 2^62, so one addition leaves it
+            // below twice the modulus
+            let product = self.value*self.factor;
+            let reduced = (product & MODULUS) + (product >> 31);
+            if reduced >= MODULUS { reduced - MODULUS } else { reduced }
+        } else {
+            // Starts and factors may be as large as they like, so multiply without overflowing
+            (self.value as u128*self.factor as u128 % self.modulus as u128) as u64
+        };
+    }
+}
+
+impl Iterator for Generator {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        // Avoid dividing for the common case of power-of-two multiples
+        let (multiple, mask) = (self.multiple, self.multiple.wrapping_sub(1));
+        let is_multiple = |value : u64| if multiple & mask == 0 {
+            value & mask == 0
+        } else {
+            value % multiple == 0
+        };
+
+        // Look for a cycle as Brent's algorithm does, by comparing each value with a saved value
+        // which is moved up to the current value after every power of two steps. Every value is
+        // checked before it is compared, so meeting the saved value means no multiple is left
+        let mut saved = self.value;
+        let (mut power, mut length) = (1u64, 0u64);
+        loop {
+            self.step();
+            if is_multiple(self.value) {
+                return Some(self.value);
+            }
+            if self.value == saved {
+                return None;
+            }
+            length += 1;
+            if length == power {
+                saved = self.value;
+                power *= 2;
+                length = 0;
+            }
+        }
+    }
+}
+
+/// Return the number of the first `pairs` pairs of values from `a` and `b` whose lowest 16 bits
+/// match.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day15::{judge, Generator, FACTOR_A, FACTOR_B};
+///
+/// let a = Generator::new(65, FACTOR_A);
+/// let b = Generator::new(8921, FACTOR_B);
+/// assert_eq!(judge(a, b, 5), 1);
+/// assert_eq!(judge(a.with_multiple(4), b.with_multiple(8), 1055), 0);
+/// assert_eq!(judge(a.with_multiple(4), b.with_multiple(8), 1056), 1);
+/// ```
+pub fn judge<A, B>(a : A, b : B, pairs : usize) -> usize
+        where A : Iterator<Item = u64>, B : Iterator<Item = u64> {
+    a.zip(b).take(pairs).filter(|&(x, y)| x & 0xffff == y & 0xffff).count()
+}
+
+/// The starting values of the two generators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Starts {
+    pub a : u64,
+    pub b : u64
+}
+
+/// Return the starting values of the generators described by the string.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day15::{parse_starts, Starts};
+///
+/// assert_eq!(parse_starts("Generator A starts with 65\nGenerator B starts with 8921"),
+///            Ok(Starts { a: 65, b: 8921 }));
+/// assert!(parse_starts("Generator B starts with 65\nGenerator A starts with 8921").is_err());
+/// ```
+pub fn parse_starts(s : &str) -> Result<Starts> {
+    let mut starts = Vec::new();
+    let mut lines = s.lines().enumerate().filter(|&(_, line)| !line.trim().is_empty());
+    for &name in ["A", "B"].iter() {
+        let (idx, line) = match lines.next() {
+            Some(line) => line,
+            None if starts.is_empty() => return Err(Error::EmptyInput { day: Day15::DAY }),
+            None => return Err(Error::parse(Day15::DAY, s.lines().count() + 1, "", "",
+                                            "a line for generator B"))
+        };
+        let error = |token : &str, expected| {
+            Error::parse(Day15::DAY, idx + 1, line, token, expected)
+        };
+        let mut tokens = line.split_whitespace();
+        for &expected in ["Generator", name, "starts", "with"].iter() {
+            let token = tokens.next().unwrap_or("");
+            if token != expected {
+                return Err(error(token, match expected {
+                    "A" => "`A`",
+                    "B" => "`B`",
+                    "Generator" => "`Generator`",
+                    "starts" => "`starts`",
+                    _ => "`with`"
+                }));
+            }
+        }
+        let token = tokens.next().unwrap_or("");
+        match token.parse::<u64>() {
+            Ok(start) if start < MODULUS => starts.push(start),
+            _ => return Err(error(token, "a starting value below 2147483647"))
+        }
+        if let Some(extra) = tokens.next() {
+            return Err(error(extra, "end of line"));
+        }
+    }
+    if let Some((idx, line)) = lines.next() {
+        return Err(Error::parse(Day15::DAY, idx + 1, line, line.trim(), "end of input"));
+    }
+
+    Ok(Starts { a: starts[0], b: starts[1] })
+}
+
+/// Solver for day 15.
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY : usize = 15;
+
+    type Input = Starts;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Starts> {
+        parse_starts(puzzle)
+    }
+
+    fn part1(&self, starts : &Starts) -> usize {
+        judge(Generator::new(starts.a, FACTOR_A), Generator::new(starts.b, FACTOR_B), PAIRS1)
+    }
+
+    fn part2(&self, starts : &Starts) -> usize {
+        judge(Generator::new(starts.a, FACTOR_A).with_multiple(4),
+              Generator::new(starts.b, FACTOR_B).with_multiple(8),
+              PAIRS2)
+    }
+}
@@ -0,0 +1,181 @@
+//! Solutions for day 16.
+
+use cycle;
+use error::{Error, Result};
+use solver::Solver;
+
+/// The number of programs dancing in the puzzle.
+pub const DANCERS : usize = 16;
+
+/// The number of times the whole dance is performed in part 2.
+pub const NDANCES : usize = 1_000_000_000;
+
+/// A single dance move.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    /// Move this many programs from the end to the front, keeping their order.
+    Spin(usize),
+    /// Swap the programs at these two positions.
+    Exchange(usize, usize),
+    /// Swap the programs with these two names.
+    Partner(u8, u8)
+}
+
+/// Return the two halves of the `/`-separated `token` from `line`.
+fn split_pair<'a>(line : &str, token : &'a str) -> Result<(&'a str, &'a str)> {
+    let mut halves = token.splitn(2, '/');
+    let first = halves.next().unwrap_or("");
+    match halves.next() {
+        Some(second) => Ok((first, second)),
+        None => Err(Error::parse(Day16::DAY, 1, line, token, "a pair separated by `/`"))
+    }
+}
+
+/// Return the comma-separated dance moves in `line`, for a dance of `ndancers` programs.
+///
+/// Moves must only refer to positions and programs in the dance, and there can be at most 26
+/// programs, named `a` onwards.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day16::{parse_moves, Move};
+///
+/// assert_eq!(parse_moves("s1,x3/4,pe/b", 5),
+///            Ok(vec![Move::Spin(1), Move::Exchange(3, 4), Move::Partner(b'e', b'b')]));
+/// assert!(parse_moves("s1,x3/5", 5).is_err());
+/// assert!(parse_moves("pa/f", 5).is_err());
+/// ```
+pub fn parse_moves(line : &str, ndancers : usize) -> Result<Vec<Move>> {
+    assert!(ndancers > 0 && ndancers <= 26);
+    let error = |token : &str, expected| Error::parse(Day16::DAY, 1, line, token, expected);
+    let position = |token : &str| match token.parse::<usize>() {
+        Ok(p) if p < ndancers => Ok(p),
+        _ => Err(error(token, "a position in the line"))
+    };
+    let program = |token : &str| match token.as_bytes() {
+        &[name] if name >= b'a' && ((name - b'a') as usize) < ndancers => Ok(name),
+        _ => Err(error(token, "the name of a program in the line"))
+    };
+
+    line.split(',').map(|token| {
+        let token = token.trim();
+        let (kind, rest) = token.split_at(token.chars().next().map_or(0, |c| c.len_utf8()));
+        match kind {
+            "s" => match rest.parse::<usize>() {
+                Ok(n) if n <= ndancers => Ok(Move::Spin(n)),
+                _ => Err(error(rest, "a spin no larger than the line"))
+            },
+            "x" => {
+                let (a, b) = split_pair(line, rest)?;
+                Ok(Move::Exchange(position(a)?, position(b)?))
+            },
+            "p" => {
+                let (a, b) = split_pair(line, rest)?;
+                Ok(Move::Partner(program(a)?, program(b)?))
+            },
+            _ => Err(error(token, "a move starting with `s`, `x` or `p`"))
+        }
+    }).collect()
+}
+
+/// Return the line of `ndancers` programs before the dance starts.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day16::lineup;
+///
+/// assert_eq!(lineup(5), b"abcde".to_vec());
+/// ```
+pub fn lineup(ndancers : usize) -> Vec<u8> {
+    (0..ndancers).map(|i| b'a' + i as u8).collect()
+}
+
+/// A whole dance, compiled so that it can be performed in a single pass over the line.
+///
+/// Spins and exchanges move programs by position, regardless of their names, while partner moves
+/// swap programs by name, regardless of their positions. The two kinds of move therefore commute,
+/// so the dance is the combination of one rearrangement of positions and one renaming.
+pub struct Dance {
+    /// The position before the dance of the program which ends up at each position.
+    positions : Vec<usize>,
+    /// The name each program ends up with, indexed by its name before the dance.
+    names : Vec<u8>
+}
+
+impl Dance {
+    /// Return the dance made of `moves`, for a line of `ndancers` programs.
+    pub fn compile(moves : &[Move], ndancers : usize) -> Dance {
+        let mut positions : Vec<usize> = (0..ndancers).collect();
+        let mut names = lineup(ndancers);
+        for m in moves {
+            match *m {
+                Move::Spin(n) => positions.rotate_right(n),
+                Move::Exchange(a, b) => positions.swap(a, b),
+                Move::Partner(a, b) => {
+                    for name in names.iter_mut() {
+                        if *name == a {
+                            *name = b;
+                        } else if *name == b {
+                            *name = a;
+                        }
+                    }
+                }
+            }
+        }
+        Dance { positions, names }
+    }
+
+    /// Return the line after performing the dance once on `line`.
+    pub fn perform(&self, line : &[u8]) -> Vec<u8> {
+        self.positions.iter().map(|&p| self.names[(line[p] - b'a') as usize]).collect()
+    }
+}
+
+/// Return the order of `ndancers` programs after performing the dance of `moves` `times` times.
+///
+/// The line eventually repeats, so only enough dances to find the cycle are performed.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day16::{dance, parse_moves};
+///
+/// let moves = parse_moves("s1,x3/4,pe/b", 5).unwrap();
+/// assert_eq!(dance(&moves, 5, 1), "baedc");
+/// assert_eq!(dance(&moves, 5, 2), "ceadb");
+/// assert_eq!(dance(&moves, 5, 1_000_000_000), "abcde");
+/// ```
+pub fn dance(moves : &[Move], ndancers : usize, times : usize) -> String {
+    let dance = Dance::compile(moves, ndancers);
+    let line = cycle::state_after(lineup(ndancers), |line| dance.perform(line), times);
+    String::from_utf8(line).unwrap()
+}
+
+/// Solver for day 16.
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY : usize = 16;
+
+    type Input = Vec<Move>;
+    type Answer1 = String;
+    type Answer2 = String;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<Move>> {
+        let line = puzzle.trim();
+        if line.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        parse_moves(line, DANCERS)
+    }
+
+    fn part1(&self, moves : &Vec<Move>) -> String {
+        dance(moves, DANCERS, 1)
+    }
+
+    fn part2(&self, moves : &Vec<Move>) -> String {
+        dance(moves, DANCERS, NDANCES)
+    }
+}
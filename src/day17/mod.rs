@@ -0,0 +1,187 @@
+//! Solutions for day 17.
+
+use std::fmt;
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The number of values inserted in part 1.
+pub const INSERTIONS1 : usize = 2017;
+
+/// The number of values inserted in part 2.
+pub const INSERTIONS2 : usize = 50_000_000;
+
+/// The circular buffer of a spinlock, which steps forward a fixed number of times before each
+/// insertion.
+///
+/// The buffer is shown in the same way as in the puzzle, with the current position in
+/// parentheses.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day17::Spinlock;
+///
+/// let mut spinlock = Spinlock::new(3);
+/// assert_eq!(spinlock.to_string(), "(0)");
+/// for _ in 0..9 {
+///     spinlock.insert();
+/// }
+/// assert_eq!(spinlock.to_string(), "0 (9) 5 7 2 4 3 8 6 1");
+/// assert_eq!(spinlock.around(0, 2), "6 1 0 (9) 5");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Spinlock {
+    buffer : Vec<usize>,
+    position : usize,
+    steps : usize
+}
+
+impl Spinlock {
+    /// Return a spinlock holding only the value 0, which steps forward `steps` times per
+    /// insertion.
+    pub fn new(steps : usize) -> Spinlock {
+        Spinlock { buffer: vec![0], position: 0, steps }
+    }
+
+    /// Step forward and insert the next value after the new position, which becomes the current
+    /// position.
+    pub fn insert(&mut self) {
+        let value = self.buffer.len();
+        // Reduce the steps first, so that adding them to the position cannot overflow
+        self.position = (self.position + self.steps % value) % value + 1;
+        self.buffer.insert(self.position, value);
+    }
+
+    /// Return the values in the buffer, starting from the value 0.
+    pub fn values(&self) -> &[usize] {
+        &self.buffer
+    }
+
+    /// Return the current position in the buffer.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Return the value after the one at `position`, wrapping around to the start.
+    pub fn value_after(&self, position : usize) -> usize {
+        self.buffer[(position + 1) % self.buffer.len()]
+    }
+
+    /// Return the values within `radius` positions of `position`, wrapping around the buffer,
+    /// shown in the same way as the whole buffer.
+    pub fn around(&self, position : usize, radius : usize) -> String {
+        let len = self.buffer.len();
+        let width = (2*radius + 1).min(len);
+        let start = (position + len - radius % len) % len;
+        let positions : Vec<usize> = (start..start + width).map(|p| p % len).collect();
+        self.show(&positions)
+    }
+
+    /// Return the values at `positions`, separated by spaces and with the current position in
+    /// parentheses.
+    fn show(&self, positions : &[usize]) -> String {
+        positions.iter().map(|&p| {
+            if p == self.position {
+                format!("({})", self.buffer[p])
+            } else {
+                self.buffer[p].to_string()
+            }
+        }).collect::<Vec<_>>().join(" ")
+    }
+}
+
+impl fmt::Display for Spinlock {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let positions : Vec<usize> = (0..self.buffer.len()).collect();
+        write!(f, "{}", self.show(&positions))
+    }
+}
+
+/// Return the value after the last one inserted, after `insertions` insertions by a spinlock
+/// stepping `steps` times per insertion.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day17::value_after_last;
+///
+/// assert_eq!(value_after_last(3, 2017), 638);
+/// ```
+pub fn value_after_last(steps : usize, insertions : usize) -> usize {
+    let mut spinlock = Spinlock::new(steps);
+    for _ in 0..insertions {
+        spinlock.insert();
+    }
+    spinlock.value_after(spinlock.position())
+}
+
+/// Return the value after 0, after `insertions` insertions by a spinlock stepping `steps` times
+/// per insertion.
+///
+/// The value 0 always stays at the start of the buffer, so only the insertions straight after it
+/// need to be recorded, and the buffer itself is never built.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day17::{value_after_zero, Spinlock};
+///
+/// assert_eq!(value_after_zero(3, 9), 9);
+/// assert_eq!(value_after_zero(usize::MAX, 9), 9);
+///
+/// let mut spinlock = Spinlock::new(3);
+/// for _ in 0..2017 {
+///     spinlock.insert();
+/// }
+/// assert_eq!(value_after_zero(3, 2017), spinlock.value_after(0));
+/// ```
+pub fn value_after_zero(steps : usize, insertions : usize) -> usize {
+    let mut position = 0;
+    let mut after_zero = 0;
+    let mut value = 1;
+    while value <= insertions {
+        // The buffer holds `value` values before `value` is inserted
+        position = (position + steps % value) % value + 1;
+        if position == 1 {
+            after_zero = value;
+        }
+        // Skip the following insertions which do not wrap around the buffer, as none of them can
+        // be straight after 0. There are none unless `steps` is smaller than the buffer.
+        let skip = (value - position).checked_div(steps).unwrap_or(0).min(insertions - value);
+        if skip > 0 {
+            position += skip*(steps + 1);
+            value += skip;
+        }
+        value += 1;
+    }
+    after_zero
+}
+
+/// Solver for day 17.
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY : usize = 17;
+
+    type Input = usize;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<usize> {
+        let line = puzzle.trim();
+        if line.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        line.parse::<usize>()
+            .map_err(|_| Error::parse(Self::DAY, 1, line, line, "a non-negative number of steps"))
+    }
+
+    fn part1(&self, steps : &usize) -> usize {
+        value_after_last(*steps, INSERTIONS1)
+    }
+
+    fn part2(&self, steps : &usize) -> usize {
+        value_after_zero(*steps, INSERTIONS2)
+    }
+}
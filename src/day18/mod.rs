@@ -0,0 +1,622 @@
+//! Solutions for day 18.
+//!
+//! The interpreter here also runs the instruction set of the coprocessor of day 23, which is
+//! parsed into the same instructions and shares the same registers and operands.
+
+use std::collections::VecDeque;
+use std::fmt;
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The number of registers, one for each lowercase letter.
+pub const NREGISTERS : usize = 26;
+
+/// A register, named by a single lowercase letter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Register(usize);
+
+impl Register {
+    /// Return the register named `name`, if it is a single lowercase letter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::Register;
+    ///
+    /// assert_eq!(Register::named("p").map(|r| r.to_string()), Some("p".to_string()));
+    /// assert!(Register::named("pc").is_none());
+    /// ```
+    pub fn named(name : &str) -> Option<Register> {
+        match name.as_bytes() {
+            &[c] if c.is_ascii_lowercase() => Some(Register((c - b'a') as usize)),
+            _ => None
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", (b'a' + self.0 as u8) as char)
+    }
+}
+
+/// The argument of an instruction, either the value of a register or an immediate value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Immediate(i64)
+}
+
+impl Operand {
+    /// Return the operand written as `token`, either a register name or an integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::{Operand, Register};
+    ///
+    /// assert_eq!(Operand::parse("-3"), Some(Operand::Immediate(-3)));
+    /// assert_eq!(Operand::parse("b"), Register::named("b").map(Operand::Register));
+    /// assert_eq!(Operand::parse("3b"), None);
+    /// ```
+    pub fn parse(token : &str) -> Option<Operand> {
+        Register::named(token).map(Operand::Register)
+                              .or_else(|| token.parse().ok().map(Operand::Immediate))
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Immediate(value) => write!(f, "{}", value)
+        }
+    }
+}
+
+/// The values of all registers of a program, which all start at zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Registers {
+    values : [i64; NREGISTERS]
+}
+
+impl Registers {
+    /// Return registers all holding zero.
+    pub fn new() -> Registers {
+        Registers::default()
+    }
+
+    /// Return the value of `register`.
+    pub fn get(&self, register : Register) -> i64 {
+        self.values[register.0]
+    }
+
+    /// Set the value of `register`.
+    pub fn set(&mut self, register : Register, value : i64) {
+        self.values[register.0] = value;
+    }
+
+    /// Return the value of `operand`.
+    pub fn value(&self, operand : Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Immediate(value) => value
+        }
+    }
+}
+
+/// Operations that update a register using its value and an operand
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IntegerOperation {
+    Set,
+    Add,
+    Subtract,
+    Multiply,
+    Modulo
+}
+
+/// Apply the `IntegerOperation` to `x` and `y`, or return `None` for `Modulo` if `y` is zero.
+///
+/// Registers are 64 bits wide and wrap on overflow, and the result of `Modulo` is never negative.
+fn apply_integer_operation(op : IntegerOperation, x : i64, y : i64) -> Option<i64> {
+    match op {
+        IntegerOperation::Set => Some(y),
+        IntegerOperation::Add => Some(x.wrapping_add(y)),
+        IntegerOperation::Subtract => Some(x.wrapping_sub(y)),
+        IntegerOperation::Multiply => Some(x.wrapping_mul(y)),
+        IntegerOperation::Modulo => x.checked_rem_euclid(y)
+    }
+}
+
+/// The operation performed by an instruction, and its operands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operation {
+    Send(Operand),
+    Integer(IntegerOperation, Register, Operand),
+    Receive(Register),
+    JumpIfGreaterThanZero(Operand, Operand),
+    JumpIfNotZero(Operand, Operand)
+}
+
+/// A single instruction, such as `add a 2` or `jgz a -1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Instruction(Operation);
+
+impl Instruction {
+    /// Return the name of the instruction's operation, such as `add` or `jgz`.
+    pub fn mnemonic(&self) -> &'static str {
+        match self.0 {
+            Operation::Send(_) => "snd",
+            Operation::Integer(IntegerOperation::Set, _, _) => "set",
+            Operation::Integer(IntegerOperation::Add, _, _) => "add",
+            Operation::Integer(IntegerOperation::Subtract, _, _) => "sub",
+            Operation::Integer(IntegerOperation::Multiply, _, _) => "mul",
+            Operation::Integer(IntegerOperation::Modulo, _, _) => "mod",
+            Operation::Receive(_) => "rcv",
+            Operation::JumpIfGreaterThanZero(_, _) => "jgz",
+            Operation::JumpIfNotZero(_, _) => "jnz"
+        }
+    }
+
+    /// Return the operands of the instruction, in the order they are written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::{parse_instructions, Operand, Register};
+    ///
+    /// let instructions = parse_instructions("add a -2\nrcv b").unwrap();
+    /// let (a, b) = (Register::named("a").unwrap(), Register::named("b").unwrap());
+    /// assert_eq!(instructions[0].operands(),
+    ///            vec![Operand::Register(a), Operand::Immediate(-2)]);
+    /// assert_eq!(instructions[1].operands(), vec![Operand::Register(b)]);
+    /// ```
+    pub fn operands(&self) -> Vec<Operand> {
+        match self.0 {
+            Operation::Send(x) => vec![x],
+            Operation::Integer(_, x, y) => vec![Operand::Register(x), y],
+            Operation::Receive(x) => vec![Operand::Register(x)],
+            Operation::JumpIfGreaterThanZero(x, y) | Operation::JumpIfNotZero(x, y) => vec![x, y]
+        }
+    }
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.mnemonic())?;
+        match self.0 {
+            Operation::Send(x) => write!(f, " {}", x),
+            Operation::Integer(_, x, y) => write!(f, " {} {}", x, y),
+            Operation::Receive(x) => write!(f, " {}", x),
+            Operation::JumpIfGreaterThanZero(x, y) | Operation::JumpIfNotZero(x, y) => {
+                write!(f, " {} {}", x, y)
+            }
+        }
+    }
+}
+
+/// The instructions understood by a program, each numbered by the day whose puzzle uses it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstructionSet {
+    /// The instructions of day 18: `snd`, `set`, `add`, `mul`, `mod`, `rcv` and `jgz`.
+    Duet = 18,
+    /// The instructions of day 23: `set`, `sub`, `mul` and `jnz`.
+    Coprocessor = 23
+}
+
+impl InstructionSet {
+    /// Return the day whose puzzle uses the instruction set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::InstructionSet;
+    ///
+    /// assert_eq!(InstructionSet::Coprocessor.day(), 23);
+    /// ```
+    pub fn day(self) -> usize {
+        self as usize
+    }
+}
+
+/// Return the instruction from the instruction `set` encoded in the string.
+fn parse_instruction(set : InstructionSet, lineno : usize, instruction : &str)
+        -> Result<Instruction> {
+    let mut components = instruction.split_whitespace();
+    // Return the next component, or an empty token if the line has ended
+    let mut next = || components.next().unwrap_or("");
+    let day = set.day();
+    let error = |token : &str, expected| Error::parse(day, lineno, instruction, token, expected);
+    let parse_register = |token : &str| {
+        Register::named(token).ok_or_else(|| error(token, "a register name"))
+    };
+    let parse_operand = |token : &str| {
+        Operand::parse(token).ok_or_else(|| error(token, "a register name or an integer"))
+    };
+
+    // Convert the operations to enum variants
+    let operation = match (set, next()) {
+        (InstructionSet::Duet, "snd") => Operation::Send(parse_operand(next())?),
+        (InstructionSet::Duet, "rcv") => Operation::Receive(parse_register(next())?),
+        (InstructionSet::Duet, "jgz") => {
+            Operation::JumpIfGreaterThanZero(parse_operand(next())?, parse_operand(next())?)
+        },
+        (InstructionSet::Coprocessor, "jnz") => {
+            Operation::JumpIfNotZero(parse_operand(next())?, parse_operand(next())?)
+        },
+        (_, name) => {
+            let op = match (set, name) {
+                (_, "set") => IntegerOperation::Set,
+                (_, "mul") => IntegerOperation::Multiply,
+                (InstructionSet::Duet, "add") => IntegerOperation::Add,
+                (InstructionSet::Duet, "mod") => IntegerOperation::Modulo,
+                (InstructionSet::Coprocessor, "sub") => IntegerOperation::Subtract,
+                (_, other) => return Err(error(other, "an instruction"))
+            };
+            Operation::Integer(op, parse_register(next())?, parse_operand(next())?)
+        }
+    };
+    match next() {
+        "" => {},
+        other => return Err(error(other, "end of line"))
+    }
+
+    Ok(Instruction(operation))
+}
+
+/// Return the list of instructions from the instruction `set` encoded in the string, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day18::{parse_program, InstructionSet};
+///
+/// let program = "set b 3\nsub b 1\njnz b -1";
+/// let instructions = parse_program(program, InstructionSet::Coprocessor).unwrap();
+/// assert_eq!(instructions[1].to_string(), "sub b 1");
+/// assert!(parse_program("set b 3\nsub b 1", InstructionSet::Duet).is_err());
+/// ```
+pub fn parse_program(s : &str, set : InstructionSet) -> Result<Vec<Instruction>> {
+    let instructions = s.lines().enumerate().filter(|&(_, line)| !line.trim().is_empty())
+                        .map(|(idx, line)| parse_instruction(set, idx + 1, line))
+                        .collect::<Result<Vec<_>>>()?;
+    if instructions.is_empty() {
+        return Err(Error::EmptyInput { day: set.day() });
+    }
+    Ok(instructions)
+}
+
+/// Return the list of duet instructions encoded in the string, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day18::parse_instructions;
+///
+/// assert_eq!(parse_instructions("set a 1\njgz a -1").map(|i| i.len()), Ok(2));
+/// assert_eq!(parse_instructions("set a 1\nmod 5 a").unwrap_err(),
+///            Error::Parse { day: 18, line: 2, column: 5, token: "5".to_string(),
+///                           expected: "a register name" });
+/// ```
+pub fn parse_instructions(s : &str) -> Result<Vec<Instruction>> {
+    parse_program(s, InstructionSet::Duet)
+}
+
+/// The reason a program stopped running.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The program sent a value, and can continue running.
+    Send(i64),
+    /// The program is waiting to receive a value into a register, and must be given one with
+    /// `Program::receive` or moved past the instruction with `Program::skip` before continuing.
+    Receive(Register),
+    /// The program jumped outside of its instructions.
+    Halt
+}
+
+/// A program running a list of instructions, which counts how many times each instruction is
+/// executed.
+pub struct Program<'a> {
+    instructions : &'a [Instruction],
+    registers : Registers,
+    counter : usize,
+    executions : Vec<usize>
+}
+
+impl<'a> Program<'a> {
+    /// Return a program at the start of the `instructions`, with register `p` holding its `id`.
+    pub fn new(instructions : &'a [Instruction], id : i64) -> Program<'a> {
+        let mut registers = Registers::new();
+        registers.set(Register::named("p").unwrap(), id);
+        Program::with_registers(instructions, registers)
+    }
+
+    /// Return a program at the start of the `instructions`, with its registers set to `registers`.
+    pub fn with_registers(instructions : &'a [Instruction], registers : Registers) -> Program<'a> {
+        Program { instructions, registers, counter: 0, executions: vec![0; instructions.len()] }
+    }
+
+    /// Return the program's registers.
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Return the index of the next instruction to execute.
+    pub fn counter(&self) -> usize {
+        self.counter
+    }
+
+    /// Return the number of times each instruction has been executed, indexed by instruction.
+    pub fn executions(&self) -> &[usize] {
+        &self.executions
+    }
+
+    /// Return the number of times instructions with the `mnemonic` have been executed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::{parse_instructions, Program};
+    ///
+    /// let instructions = parse_instructions("set a 3\nmul b a\nadd a -1\njgz a -2").unwrap();
+    /// let mut program = Program::new(&instructions, 0);
+    /// program.run();
+    /// assert_eq!(program.executions(), &[1, 3, 3, 3]);
+    /// assert_eq!(program.executions_of("mul"), 3);
+    /// ```
+    pub fn executions_of(&self, mnemonic : &str) -> usize {
+        self.instructions.iter().zip(self.executions.iter())
+                         .filter(|&(instruction, _)| instruction.mnemonic() == mnemonic)
+                         .map(|(_, &count)| count).sum()
+    }
+
+    /// Move the program counter by `offset`, returning false if it leaves the instructions.
+    fn jump(&mut self, offset : i64) -> bool {
+        let target = (self.counter as i64).wrapping_add(offset);
+        if target < 0 || target >= self.instructions.len() as i64 {
+            self.counter = self.instructions.len();
+            false
+        } else {
+            self.counter = target as usize;
+            true
+        }
+    }
+
+    /// Run the program until it sends a value, waits to receive one, or halts.
+    ///
+    /// Returns an error if an instruction takes a remainder modulo zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::{parse_instructions, Event, Program};
+    ///
+    /// let instructions = parse_instructions("set a 9223372036854775807\nadd a 1\nsnd a").unwrap();
+    /// assert_eq!(Program::new(&instructions, 0).run(), Ok(Event::Send(i64::MIN)));
+    ///
+    /// let instructions = parse_instructions("set a 7\nmod a b").unwrap();
+    /// assert!(Program::new(&instructions, 0).run().is_err());
+    /// ```
+    pub fn run(&mut self) -> Result<Event> {
+        // Without a breakpoint, the program can only stop with an event
+        self.execute(None).map(|event| event.unwrap())
+    }
+
+    /// Run the program as `run` does, but stop before executing the instruction at `breakpoint`,
+    /// returning `None` when the breakpoint is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day18::{parse_instructions, Program, Register};
+    ///
+    /// let instructions = parse_instructions("set a 3\nadd b a\nadd a -1\njgz a -2").unwrap();
+    /// let mut program = Program::new(&instructions, 0);
+    /// assert_eq!(program.run_to(3), Ok(None));
+    /// assert_eq!(program.registers().get(Register::named("b").unwrap()), 3);
+    /// ```
+    pub fn run_to(&mut self, breakpoint : usize) -> Result<Option<Event>> {
+        self.execute(Some(breakpoint))
+    }
+
+    /// Run the program until it sends a value, waits to receive one, halts, or reaches the
+    /// `breakpoint`.
+    fn execute(&mut self, breakpoint : Option<usize>) -> Result<Option<Event>> {
+        while let Some(&instruction) = self.instructions.get(self.counter) {
+            let Instruction(operation) = instruction;
+            if breakpoint == Some(self.counter) {
+                return Ok(None);
+            }
+            if let Operation::Receive(x) = operation {
+                // Receiving is only counted once the program moves past the instruction
+                return Ok(Some(Event::Receive(x)));
+            }
+            self.executions[self.counter] += 1;
+            let mut offset = 1;
+            match operation {
+                Operation::Send(x) => {
+                    self.counter += 1;
+                    return Ok(Some(Event::Send(self.registers.value(x))));
+                },
+                Operation::Integer(op, x, y) => {
+                    let result = apply_integer_operation(op, self.registers.get(x),
+                                                         self.registers.value(y));
+                    // Only `mod` can fail, and it is only in the instruction set of day 18
+                    let result = result.ok_or_else(|| Error::Invalid {
+                        day: Day18::DAY,
+                        message: format!("`{}` at instruction {} takes a remainder modulo zero",
+                                         instruction, self.counter + 1)
+                    })?;
+                    self.registers.set(x, result);
+                },
+                Operation::Receive(_) => {},
+                Operation::JumpIfGreaterThanZero(x, y) => if self.registers.value(x) > 0 {
+                    offset = self.registers.value(y);
+                },
+                Operation::JumpIfNotZero(x, y) => if self.registers.value(x) != 0 {
+                    offset = self.registers.value(y);
+                }
+            }
+            if !self.jump(offset) {
+                break;
+            }
+        }
+        Ok(Some(Event::Halt))
+    }
+
+    /// Store `value` in the register the program is waiting to receive into, and move past the
+    /// receiving instruction.
+    pub fn receive(&mut self, value : i64) {
+        if let Some(&Instruction(Operation::Receive(x))) = self.instructions.get(self.counter) {
+            self.registers.set(x, value);
+            self.executions[self.counter] += 1;
+            self.counter += 1;
+        }
+    }
+
+    /// Move past the receiving instruction the program is waiting at without receiving a value.
+    pub fn skip(&mut self) {
+        if let Some(&Instruction(Operation::Receive(_))) = self.instructions.get(self.counter) {
+            self.executions[self.counter] += 1;
+            self.counter += 1;
+        }
+    }
+}
+
+/// Return the frequency of the first sound recovered when running `instructions` with sound
+/// semantics, if any sound is recovered before the program halts, or an error if the program
+/// fails.
+///
+/// `snd X` plays a sound with a frequency of `X`, and `rcv X` recovers the frequency of the last
+/// sound played if `X` is not zero.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day18::{parse_instructions, recovered_frequency};
+///
+/// let instructions = parse_instructions("set a 1
+/// add a 2
+/// mul a a
+/// mod a 5
+/// snd a
+/// set a 0
+/// rcv a
+/// jgz a -1
+/// set a 1
+/// jgz a -2").unwrap();
+/// assert_eq!(recovered_frequency(&instructions), Ok(Some(4)));
+/// ```
+pub fn recovered_frequency(instructions : &[Instruction]) -> Result<Option<i64>> {
+    let mut program = Program::new(instructions, 0);
+    let mut last_played = None;
+    loop {
+        match program.run()? {
+            Event::Send(frequency) => last_played = Some(frequency),
+            Event::Receive(x) => if program.registers().get(x) != 0 && last_played.is_some() {
+                return Ok(last_played);
+            } else {
+                program.skip();
+            },
+            Event::Halt => return Ok(None)
+        }
+    }
+}
+
+/// The outcome of running two programs as a duet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Duet {
+    /// The number of values sent by each program.
+    pub sent : [usize; 2],
+    /// True if the programs stopped because they were both waiting to receive, rather than
+    /// because they both halted or one halted while the other waited.
+    pub deadlock : bool
+}
+
+/// Return the outcome of running two copies of `instructions` with message passing semantics, or
+/// an error if either copy fails.
+///
+/// The copies have ids 0 and 1, and `snd X` sends `X` to the other copy, where it is queued until
+/// `rcv X` receives it. The copies run until neither can make progress.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day18::{duet, parse_instructions};
+///
+/// let instructions = parse_instructions("snd 1
+/// snd 2
+/// snd p
+/// rcv a
+/// rcv b
+/// rcv c
+/// rcv d").unwrap();
+/// let outcome = duet(&instructions).unwrap();
+/// assert_eq!(outcome.sent, [3, 3]);
+/// assert!(outcome.deadlock);
+///
+/// assert!(!duet(&parse_instructions("snd p").unwrap()).unwrap().deadlock);
+/// ```
+pub fn duet(instructions : &[Instruction]) -> Result<Duet> {
+    let mut programs = [Program::new(instructions, 0), Program::new(instructions, 1)];
+    let mut queues = [VecDeque::new(), VecDeque::new()];
+    let mut sent = [0, 0];
+    let mut waiting = [false, false];
+    loop {
+        let mut progressed = false;
+        for id in 0..2 {
+            waiting[id] = false;
+            loop {
+                match programs[id].run()? {
+                    Event::Send(value) => {
+                        queues[1 - id].push_back(value);
+                        sent[id] += 1;
+                        progressed = true;
+                    },
+                    Event::Receive(_) => match queues[id].pop_front() {
+                        Some(value) => {
+                            programs[id].receive(value);
+                            progressed = true;
+                        },
+                        None => {
+                            waiting[id] = true;
+                            break;
+                        }
+                    },
+                    Event::Halt => break
+                }
+            }
+        }
+        if !progressed {
+            return Ok(Duet { sent, deadlock: waiting[0] && waiting[1] });
+        }
+    }
+}
+
+/// Solver for day 18.
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY : usize = 18;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = Result<i64>;
+    type Answer2 = Result<usize>;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<Instruction>> {
+        parse_instructions(puzzle)
+    }
+
+    fn part1(&self, instructions : &Vec<Instruction>) -> Result<i64> {
+        recovered_frequency(instructions)?.ok_or_else(|| Error::Invalid {
+            day: Self::DAY,
+            message: "no frequency is recovered before the program halts".to_string()
+        })
+    }
+
+    fn part2(&self, instructions : &Vec<Instruction>) -> Result<usize> {
+        duet(instructions).map(|outcome| outcome.sent[1])
+    }
+}
@@ -0,0 +1,122 @@
+//! Solutions for day 19.
+
+use error::{Error, Result};
+use grid::{Coordinate, Direction, Grid};
+use solver::Solver;
+
+/// The route taken by a packet through a routing diagram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Route {
+    /// The letters passed, in order.
+    pub letters : String,
+    /// The number of squares visited, including the first.
+    pub steps : usize
+}
+
+/// Return the routing diagram encoded in the string, as a grid of characters.
+///
+/// The diagram may only contain spaces, `|`, `-`, `+` and uppercase letters, and lines shorter
+/// than the longest are padded with spaces.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day19::parse_diagram;
+///
+/// assert_eq!(parse_diagram(" |\n +-A").map(|grid| grid.width()), Ok(4));
+/// assert!(parse_diagram(" |\n +-a").is_err());
+/// ```
+pub fn parse_diagram(s : &str) -> Result<Grid<char>> {
+    let mut rows = Vec::new();
+    for (idx, line) in s.lines().enumerate() {
+        if let Some((column, c)) = line.char_indices().find(|&(_, c)| {
+            !(c == ' ' || c == '|' || c == '-' || c == '+' || c.is_ascii_uppercase())
+        }) {
+            return Err(Error::parse(Day19::DAY, idx + 1, line, &line[column..column + c.len_utf8()],
+                                    "a space, `|`, `-`, `+` or an uppercase letter"));
+        }
+        rows.push(line.chars().collect());
+    }
+    if rows.iter().all(|row : &Vec<char>| row.iter().all(|&c| c == ' ')) {
+        return Err(Error::EmptyInput { day: Day19::DAY });
+    }
+
+    Ok(Grid::from_rows(rows, ' '))
+}
+
+/// Return the route followed through the `diagram`, starting from the first line on the top row
+/// and moving down.
+///
+/// The route continues straight through every square until it reaches a `+`, where it turns onto
+/// whichever line continues to its left or right. It ends when there is no line left to follow.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day19::{follow, parse_diagram};
+///
+/// let diagram = parse_diagram(&[
+///     "     |          ",
+///     "     |  +--+    ",
+///     "     A  |  C    ",
+///     " F---|----E|--+ ",
+///     "     |  |  |  D ",
+///     "     +B-+  +--+ "
+/// ].join("\n")).unwrap();
+/// let route = follow(&diagram);
+/// assert_eq!(route.letters, "ABCDEF");
+/// assert_eq!(route.steps, 38);
+/// ```
+pub fn follow(diagram : &Grid<char>) -> Route {
+    let is_path = |position : Coordinate| diagram.get(position).is_some_and(|&c| c != ' ');
+    let mut route = Route { letters: String::new(), steps: 0 };
+    let mut position = match diagram.position(|&c| c != ' ') {
+        Some(position) if position.y as usize == diagram.height() - 1 => position,
+        _ => return route
+    };
+    let mut direction = Direction::Down;
+
+    while is_path(position) {
+        route.steps += 1;
+        match diagram.get(position) {
+            Some(&'+') => {
+                // Turn onto the line to the left or right; the route ends if neither continues
+                let left = direction.turn_left();
+                let right = direction.turn_right();
+                if is_path(position + left.step()) {
+                    direction = left;
+                } else if is_path(position + right.step()) {
+                    direction = right;
+                }
+            },
+            Some(&c) if c.is_ascii_uppercase() => route.letters.push(c),
+            _ => {}
+        }
+        position = position + direction.step();
+    }
+
+    route
+}
+
+/// Solver for day 19.
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY : usize = 19;
+
+    type Input = Grid<char>;
+    type Answer1 = String;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Grid<char>> {
+        parse_diagram(puzzle)
+    }
+
+    fn part1(&self, diagram : &Grid<char>) -> String {
+        follow(diagram).letters
+    }
+
+    fn part2(&self, diagram : &Grid<char>) -> usize {
+        follow(diagram).steps
+    }
+}
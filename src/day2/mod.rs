@@ -0,0 +1,177 @@
+//! Solutions for day 2.
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The smallest and largest values of a spreadsheet row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowRange {
+    /// The index of the row in the spreadsheet, starting from 0, which is one less than its line
+    /// number.
+    pub row : usize,
+    /// The smallest value in the row.
+    pub min : u32,
+    /// The largest value in the row.
+    pub max : u32
+}
+
+impl RowRange {
+    /// Return the contribution of the row to the checksum.
+    pub fn difference(&self) -> u32 {
+        self.max - self.min
+    }
+}
+
+/// The pair of values in a spreadsheet row where one evenly divides the other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DivisiblePair {
+    /// The index of the row in the spreadsheet, starting from 0, which is one less than its line
+    /// number.
+    pub row : usize,
+    /// The larger value of the pair.
+    pub dividend : u32,
+    /// The smaller value of the pair, which evenly divides `dividend`.
+    pub divisor : u32
+}
+
+impl DivisiblePair {
+    /// Return the contribution of the pair to the checksum.
+    pub fn quotient(&self) -> u32 {
+        self.dividend/self.divisor
+    }
+}
+
+/// Return the rows of the spreadsheet encoded in the string.
+///
+/// Values within a row may be separated by any whitespace, and rows may have different lengths.
+/// Each line is a row, so that row indices match line numbers; empty lines are empty rows, which
+/// contribute nothing to either checksum.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day2::parse_spreadsheet;
+///
+/// assert_eq!(parse_spreadsheet("5 1\t9 5\n\n7 5 3"),
+///            Ok(vec![vec![5, 1, 9, 5], vec![], vec![7, 5, 3]]));
+/// assert!(parse_spreadsheet("5 1 x 5").is_err());
+/// assert_eq!(parse_spreadsheet("\n  \n"), Err(Error::EmptyInput { day: 2 }));
+/// ```
+pub fn parse_spreadsheet(s : &str) -> Result<Vec<Vec<u32>>> {
+    let rows = s.lines().enumerate().map(|(idx, line)| {
+        line.split_whitespace().map(|token| {
+            token.parse::<u32>().map_err(|_| {
+                Error::parse(Day2::DAY, idx + 1, line, token, "a non-negative integer")
+            })
+        }).collect()
+    }).collect::<Result<Vec<Vec<u32>>>>()?;
+    if rows.iter().all(|row| row.is_empty()) {
+        return Err(Error::EmptyInput { day: Day2::DAY });
+    }
+
+    Ok(rows)
+}
+
+/// Return the smallest and largest values of each row of the `spreadsheet`.
+///
+/// Empty rows are omitted.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day2::{row_ranges, RowRange};
+///
+/// let spreadsheet = vec![vec![5, 1, 9, 5], vec![7, 5, 3], vec![2, 4, 6, 8]];
+/// assert_eq!(row_ranges(&spreadsheet)[1], RowRange { row: 1, min: 3, max: 7 });
+/// ```
+pub fn row_ranges(spreadsheet : &[Vec<u32>]) -> Vec<RowRange> {
+    spreadsheet.iter().enumerate().filter_map(|(row, values)| {
+        let min = values.iter().min()?;
+        let max = values.iter().max()?;
+        Some(RowRange { row, min: *min, max: *max })
+    }).collect()
+}
+
+/// Return the sum over all rows of the difference between the largest and smallest values.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day2::checksum;
+///
+/// assert_eq!(checksum(&[vec![5, 1, 9, 5], vec![7, 5, 3], vec![2, 4, 6, 8]]), 18);
+/// assert_eq!(checksum(&[vec![0, u32::MAX], vec![u32::MAX, 0]]), 2*u64::from(u32::MAX));
+/// ```
+pub fn checksum(spreadsheet : &[Vec<u32>]) -> u64 {
+    // The differences of several rows may not fit in a `u32` together
+    row_ranges(spreadsheet).iter().map(|r| u64::from(r.difference())).sum()
+}
+
+/// Return the first pair of values in each row where one evenly divides the other.
+///
+/// Rows with no such pair are omitted.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day2::{divisible_pairs, DivisiblePair};
+///
+/// let spreadsheet = vec![vec![5, 9, 2, 8], vec![9, 4, 7, 3], vec![3, 5, 7]];
+/// assert_eq!(divisible_pairs(&spreadsheet), vec![
+///     DivisiblePair { row: 0, dividend: 8, divisor: 2 },
+///     DivisiblePair { row: 1, dividend: 9, divisor: 3 }
+/// ]);
+/// ```
+pub fn divisible_pairs(spreadsheet : &[Vec<u32>]) -> Vec<DivisiblePair> {
+    spreadsheet.iter().enumerate().filter_map(|(row, values)| {
+        // Compare each value with every later value, in both orders
+        for (i, &a) in values.iter().enumerate() {
+            for &b in values.iter().skip(i + 1) {
+                let (dividend, divisor) = if a >= b { (a, b) } else { (b, a) };
+                if divisor != 0 && dividend % divisor == 0 {
+                    return Some(DivisiblePair { row, dividend, divisor });
+                }
+            }
+        }
+        None
+    }).collect()
+}
+
+/// Return the sum over all rows of the quotient of the evenly divisible pair of values.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day2::divisible_checksum;
+///
+/// assert_eq!(divisible_checksum(&[vec![5, 9, 2, 8], vec![9, 4, 7, 3], vec![3, 8, 6, 5]]), 9);
+/// assert_eq!(divisible_checksum(&[vec![1, u32::MAX], vec![u32::MAX, 1]]), 2*u64::from(u32::MAX));
+/// ```
+pub fn divisible_checksum(spreadsheet : &[Vec<u32>]) -> u64 {
+    // The quotients of several rows may not fit in a `u32` together
+    divisible_pairs(spreadsheet).iter().map(|p| u64::from(p.quotient())).sum()
+}
+
+/// Solver for day 2.
+pub struct Day2;
+
+impl Solver for Day2 {
+    const DAY : usize = 2;
+
+    type Input = Vec<Vec<u32>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<Vec<u32>>> {
+        parse_spreadsheet(puzzle)
+    }
+
+    fn part1(&self, spreadsheet : &Vec<Vec<u32>>) -> u64 {
+        checksum(spreadsheet)
+    }
+
+    fn part2(&self, spreadsheet : &Vec<Vec<u32>>) -> u64 {
+        divisible_checksum(spreadsheet)
+    }
+}
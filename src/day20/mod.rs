@@ -0,0 +1,385 @@
+//! Solutions for day 20.
+
+use std::convert::TryFrom;
+use std::ops::Add;
+
+use error::{Error, Result};
+use grid::GridCoordinate;
+use solver::Solver;
+
+/// A vector in three dimensions with integer components.
+///
+/// Adding vectors and measuring distances between them panic if the result does not fit, in
+/// release builds as well as debug builds, rather than giving a wrong result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vector {
+    pub x : i64,
+    pub y : i64,
+    pub z : i64
+}
+
+impl Vector {
+    /// Return the components of the vector as an array.
+    fn components(&self) -> [i64; 3] {
+        [self.x, self.y, self.z]
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, other : Vector) -> Vector {
+        let add = |a : i64, b : i64| a.checked_add(b).expect("vector component overflowed");
+        Vector { x: add(self.x, other.x), y: add(self.y, other.y), z: add(self.z, other.z) }
+    }
+}
+
+/// Vectors are one step apart if they differ by one along a single axis, so that distances are
+/// Manhattan distances.
+impl GridCoordinate for Vector {
+    fn origin() -> Vector {
+        Vector::default()
+    }
+
+    fn unit_steps() -> Vec<Vector> {
+        vec![
+            Vector { x: 1, y: 0, z: 0 },
+            Vector { x: -1, y: 0, z: 0 },
+            Vector { x: 0, y: 1, z: 0 },
+            Vector { x: 0, y: -1, z: 0 },
+            Vector { x: 0, y: 0, z: 1 },
+            Vector { x: 0, y: 0, z: -1 }
+        ]
+    }
+
+    fn distance(&self, other : &Vector) -> usize {
+        let differences = [self.x.abs_diff(other.x), self.y.abs_diff(other.y),
+                           self.z.abs_diff(other.z)];
+        differences.iter().try_fold(0u64, |sum, &d| sum.checked_add(d))
+                   .and_then(|d| usize::try_from(d).ok()).expect("distance overflowed")
+    }
+}
+
+/// A particle, which on each tick adds its acceleration to its velocity and then its velocity to
+/// its position.
+///
+/// The motion of particles is worked out exactly as long as every component of their position,
+/// velocity and acceleration fits in an `i32`, which `parse_particles` ensures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Particle {
+    pub position : Vector,
+    pub velocity : Vector,
+    pub acceleration : Vector
+}
+
+impl Particle {
+    /// Move the particle forward by one tick.
+    pub fn tick(&mut self) {
+        self.velocity = self.velocity + self.acceleration;
+        self.position = self.position + self.velocity;
+    }
+
+    /// Return the position of the particle after `ticks` ticks, or `None` if it, or any step of
+    /// computing it, does not fit in an `i64`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day20::parse_particles;
+    ///
+    /// let mut particle = parse_particles("p=<3,0,0>, v=<2,0,0>, a=<-1,0,0>").unwrap()[0];
+    /// for _ in 0..3 {
+    ///     particle.tick();
+    /// }
+    /// assert_eq!(particle.position_after(0), Some(particle.position));
+    /// assert_eq!(particle.position.x, 3);
+    /// assert_eq!(particle.position_after(2).map(|p| p.x), Some(-2));
+    /// assert_eq!(particle.position_after(3_000_000_000).map(|p| p.x),
+    ///            Some(-4_500_000_004_499_999_997));
+    /// assert_eq!(particle.position_after(5_000_000_000), None);
+    /// ```
+    pub fn position_after(&self, ticks : i64) -> Option<Vector> {
+        // Halve whichever of `ticks` and `ticks + 1` is even before multiplying, so that the
+        // triangular number only overflows if it is itself too large
+        let next = ticks.checked_add(1)?;
+        let triangle = if ticks % 2 == 0 {
+            (ticks/2).checked_mul(next)?
+        } else {
+            ticks.checked_mul(next/2)?
+        };
+        let at = |p : i64, v : i64, a : i64| {
+            p.checked_add(v.checked_mul(ticks)?)?.checked_add(a.checked_mul(triangle)?)
+        };
+        let (p, v, a) = (self.position, self.velocity, self.acceleration);
+        Some(Vector { x: at(p.x, v.x, a.x)?, y: at(p.y, v.y, a.y)?, z: at(p.z, v.z, a.z)? })
+    }
+
+    /// Return the coefficients of the Manhattan distance of the particle from the origin after
+    /// enough ticks, as the acceleration, velocity and position terms of `a t(t + 1)/2 + v t + p`.
+    ///
+    /// Eventually each component of the position keeps the sign of the first non-zero term out of
+    /// its acceleration, velocity and position, so the distance is a single polynomial.
+    fn long_run_distance(&self) -> (i128, i128, i128) {
+        let (p, v, a) = (self.position.components(), self.velocity.components(),
+                         self.acceleration.components());
+        let mut distance = (0, 0, 0);
+        for axis in 0..3 {
+            let sign = [a[axis], v[axis], p[axis]].iter().map(|c| c.signum())
+                                                   .find(|&s| s != 0).unwrap_or(0);
+            distance.0 += i128::from(sign*a[axis]);
+            distance.1 += i128::from(sign*v[axis]);
+            distance.2 += i128::from(sign*p[axis]);
+        }
+        distance
+    }
+}
+
+/// Return the first whitespace-separated token of `rest`, or an empty token at its end.
+fn first_token(rest : &str) -> &str {
+    rest.split_whitespace().next().unwrap_or(&rest[rest.len()..])
+}
+
+/// Return the particle described by the line `p=<X,Y,Z>, v=<X,Y,Z>, a=<X,Y,Z>`.
+fn parse_particle(lineno : usize, line : &str) -> Result<Particle> {
+    let error = |token : &str, expected| Error::parse(Day20::DAY, lineno, line, token, expected);
+    let mut rest = line.trim_start();
+    let mut vectors = [Vector::default(); 3];
+
+    let prefixes = [("p=<", "`p=<`"), ("v=<", "`v=<`"), ("a=<", "`a=<`")];
+    for (idx, &(prefix, expected)) in prefixes.iter().enumerate() {
+        if idx > 0 {
+            rest = rest.strip_prefix(',').ok_or_else(|| error(first_token(rest), "`,`"))?;
+            rest = rest.trim_start();
+        }
+        rest = rest.strip_prefix(prefix).ok_or_else(|| error(first_token(rest), expected))?;
+        let end = rest.find('>').ok_or_else(|| error(&rest[rest.len()..], "`>`"))?;
+        let components : Vec<&str> = rest[..end].split(',').map(|c| c.trim()).collect();
+        if components.len() != 3 {
+            return Err(error(&rest[..end], "three comma-separated integers"));
+        }
+        let mut values = [0; 3];
+        for (value, component) in values.iter_mut().zip(components) {
+            *value = component.parse::<i32>()
+                              .map_err(|_| error(component, "an integer that fits in 32 bits"))?
+                              .into();
+        }
+        vectors[idx] = Vector { x: values[0], y: values[1], z: values[2] };
+        rest = rest[end + 1..].trim_start();
+    }
+    if !rest.is_empty() {
+        return Err(error(first_token(rest), "end of line"));
+    }
+
+    Ok(Particle { position: vectors[0], velocity: vectors[1], acceleration: vectors[2] })
+}
+
+/// Return the list of particles encoded in the string, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day20::{parse_particles, Vector};
+///
+/// let particles = parse_particles("p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>").unwrap();
+/// assert_eq!(particles[0].acceleration, Vector { x: -1, y: 0, z: 0 });
+/// assert_eq!(parse_particles("p=<3,0,0>, v=<2,x,0>, a=<-1,0,0>").unwrap_err(),
+///            Error::Parse { day: 20, line: 1, column: 17, token: "x".to_string(),
+///                           expected: "an integer that fits in 32 bits" });
+/// assert!(parse_particles("p=<3,0,0>, v=<2,0,0>, a=<-1,0,-2147483649>").is_err());
+/// ```
+pub fn parse_particles(s : &str) -> Result<Vec<Particle>> {
+    let particles = s.lines().enumerate().filter(|&(_, line)| !line.trim().is_empty())
+                     .map(|(idx, line)| parse_particle(idx + 1, line))
+                     .collect::<Result<Vec<_>>>()?;
+    if particles.is_empty() {
+        return Err(Error::EmptyInput { day: Day20::DAY });
+    }
+    Ok(particles)
+}
+
+/// Return the index of the particle which stays closest to the origin in the long run.
+///
+/// Particles are compared by the size of their acceleration, then by their velocity and then by
+/// their position along the direction they eventually move in. Ties go to the lowest index.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day20::{closest_in_long_run, parse_particles};
+///
+/// let particles = parse_particles("p=< 3,0,0>, v=< 2,0,0>, a=<-1,0,0>
+/// p=< 4,0,0>, v=< 0,0,0>, a=<-2,0,0>").unwrap();
+/// assert_eq!(closest_in_long_run(&particles), Some(0));
+///
+/// // Moving away from the origin is worse than moving towards it at the same speed
+/// let particles = parse_particles("p=<5,0,0>, v=<1,0,0>, a=<1,0,0>
+/// p=<5,0,0>, v=<-1,0,0>, a=<1,0,0>").unwrap();
+/// assert_eq!(closest_in_long_run(&particles), Some(1));
+/// ```
+pub fn closest_in_long_run(particles : &[Particle]) -> Option<usize> {
+    particles.iter().enumerate()
+             .min_by(|&(i, a), &(j, b)| {
+                 a.long_run_distance().cmp(&b.long_run_distance()).then(i.cmp(&j))
+             })
+             .map(|(idx, _)| idx)
+}
+
+/// Return the non-negative integer roots of `a t^2 + b t + c`, or `None` if every `t` is a root.
+///
+/// The coefficients are those of `collision_times`, which are small enough that the discriminant
+/// cannot overflow.
+fn integer_roots(a : i128, b : i128, c : i128) -> Option<Vec<i128>> {
+    let mut roots = Vec::new();
+    if a == 0 {
+        if b == 0 {
+            return if c == 0 { None } else { Some(roots) };
+        }
+        if c % b == 0 {
+            roots.push(-c/b);
+        }
+    } else {
+        let discriminant = b*b - 4*a*c;
+        if discriminant >= 0 {
+            let root = discriminant.isqrt();
+            if root*root == discriminant {
+                for &numerator in [-b - root, -b + root].iter() {
+                    if numerator % (2*a) == 0 {
+                        roots.push(numerator/(2*a));
+                    }
+                }
+            }
+        }
+    }
+    roots.retain(|&t| t >= 0);
+    roots.sort_unstable();
+    roots.dedup();
+    Some(roots)
+}
+
+/// Return the ticks after which particles `a` and `b` are in the same position, in order.
+///
+/// Returns `None` if they are always in the same position.
+///
+/// The arithmetic is done with `i128`, in which it is exact for particles whose components fit in
+/// an `i32`.
+fn collision_times(a : &Particle, b : &Particle) -> Option<Vec<i128>> {
+    let (pa, pb) = (a.position.components(), b.position.components());
+    let (va, vb) = (a.velocity.components(), b.velocity.components());
+    let (aa, ab) = (a.acceleration.components(), b.acceleration.components());
+    // Twice the difference in positions after `t` ticks, as the coefficients of a quadratic in `t`
+    let quadratics : Vec<(i128, i128, i128)> = (0..3).map(|axis| {
+        let difference = |x : [i64; 3], y : [i64; 3]| i128::from(x[axis]) - i128::from(y[axis]);
+        let (dp, dv, da) = (difference(pa, pb), difference(va, vb), difference(aa, ab));
+        (da, 2*dv + da, 2*dp)
+    }).collect();
+
+    // Candidates come from the first axis where the particles are not always level
+    let candidates = quadratics.iter().filter_map(|&(a, b, c)| integer_roots(a, b, c)).next()?;
+    Some(candidates.into_iter()
+                   .filter(|&t| t > 0 && quadratics.iter().all(|&(a, b, c)| a*t*t + b*t + c == 0))
+                   .collect())
+}
+
+/// The destruction of particles which are in the same position after a tick.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Collision {
+    /// The tick after which the particles collide.
+    pub tick : i128,
+    /// The indices of the particles destroyed, in order.
+    pub particles : Vec<usize>
+}
+
+/// Return every collision between `particles`, in order, removing the particles destroyed by
+/// each collision from those that can collide later.
+///
+/// The collision times of each pair of particles are found exactly, so the particles are only
+/// followed until the last collision that is possible.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day20::{collisions, parse_particles, Collision};
+///
+/// let particles = parse_particles("p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
+/// p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
+/// p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
+/// p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>").unwrap();
+/// assert_eq!(collisions(&particles), vec![Collision { tick: 2, particles: vec![0, 1, 2] }]);
+/// ```
+pub fn collisions(particles : &[Particle]) -> Vec<Collision> {
+    // Only the first meeting of each pair matters, as a collision destroys both particles
+    let mut meetings = Vec::new();
+    for (i, a) in particles.iter().enumerate() {
+        for (j, b) in particles.iter().enumerate().skip(i + 1) {
+            match collision_times(a, b) {
+                None => meetings.push((1, i, j)),
+                Some(times) => if let Some(&tick) = times.first() {
+                    meetings.push((tick, i, j));
+                }
+            }
+        }
+    }
+    meetings.sort_unstable();
+
+    let mut destroyed = vec![false; particles.len()];
+    let mut collisions : Vec<Collision> = Vec::new();
+    for group in meetings.chunk_by(|x, y| x.0 == y.0) {
+        // Particles destroyed after this tick can still collide with several others at once
+        let mut colliding : Vec<usize> = group.iter()
+                                              .filter(|&&(_, i, j)| !destroyed[i] && !destroyed[j])
+                                              .flat_map(|&(_, i, j)| vec![i, j]).collect();
+        if colliding.is_empty() {
+            continue;
+        }
+        colliding.sort_unstable();
+        colliding.dedup();
+        for &idx in colliding.iter() {
+            destroyed[idx] = true;
+        }
+        collisions.push(Collision { tick: group[0].0, particles: colliding });
+    }
+
+    collisions
+}
+
+/// Return the number of particles left once every possible collision has happened.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day20::{parse_particles, remaining_after_collisions};
+///
+/// let particles = parse_particles("p=<-6,0,0>, v=< 3,0,0>, a=< 0,0,0>
+/// p=<-4,0,0>, v=< 2,0,0>, a=< 0,0,0>
+/// p=<-2,0,0>, v=< 1,0,0>, a=< 0,0,0>
+/// p=< 3,0,0>, v=<-1,0,0>, a=< 0,0,0>").unwrap();
+/// assert_eq!(remaining_after_collisions(&particles), 1);
+/// ```
+pub fn remaining_after_collisions(particles : &[Particle]) -> usize {
+    particles.len() - collisions(particles).iter().map(|c| c.particles.len()).sum::<usize>()
+}
+
+/// Solver for day 20.
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY : usize = 20;
+
+    type Input = Vec<Particle>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<Particle>> {
+        parse_particles(puzzle)
+    }
+
+    fn part1(&self, particles : &Vec<Particle>) -> usize {
+        // Parsing guarantees there is at least one particle
+        closest_in_long_run(particles).unwrap()
+    }
+
+    fn part2(&self, particles : &Vec<Particle>) -> usize {
+        remaining_after_collisions(particles)
+    }
+}
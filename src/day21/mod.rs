@@ -0,0 +1,464 @@
+//! Solutions for day 21.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The pattern the art program starts from.
+pub const START : &str = ".#./..#/###";
+
+/// The number of iterations in part 1.
+pub const ITERATIONS1 : usize = 5;
+
+/// The number of iterations in part 2.
+pub const ITERATIONS2 : usize = 18;
+
+/// A square grid of pixels, each either on or off.
+///
+/// Patterns are written in the same way as in the rules, with `#` for pixels that are on, `.` for
+/// pixels that are off, and `/` between rows. They are displayed with one row per line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Pattern {
+    size : usize,
+    /// The pixels in row-major order, starting from the top row.
+    pixels : Vec<bool>
+}
+
+impl Pattern {
+    /// Return a pattern of `size` by `size` pixels, all off.
+    pub fn new(size : usize) -> Pattern {
+        Pattern { size, pixels: vec![false; size*size] }
+    }
+
+    /// Return the pattern written as `s`, if it is square and contains only `#`, `.` and `/`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::Pattern;
+    ///
+    /// let pattern = Pattern::parse(".#/#.").unwrap();
+    /// assert_eq!(pattern.size(), 2);
+    /// assert!(pattern.get(0, 1));
+    /// assert!(Pattern::parse(".#/#").is_none());
+    /// ```
+    pub fn parse(s : &str) -> Option<Pattern> {
+        let rows : Vec<&str> = s.split('/').collect();
+        let size = rows.len();
+        let mut pixels = Vec::with_capacity(size*size);
+        for row in rows {
+            if row.len() != size {
+                return None;
+            }
+            for c in row.chars() {
+                match c {
+                    '#' => pixels.push(true),
+                    '.' => pixels.push(false),
+                    _ => return None
+                }
+            }
+        }
+        Some(Pattern { size, pixels })
+    }
+
+    /// Return the number of rows and columns of the pattern.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Return true if the pixel in `row` and `column` is on.
+    pub fn get(&self, row : usize, column : usize) -> bool {
+        self.pixels[row*self.size + column]
+    }
+
+    /// Turn the pixel in `row` and `column` on or off.
+    pub fn set(&mut self, row : usize, column : usize, on : bool) {
+        self.pixels[row*self.size + column] = on;
+    }
+
+    /// Return the number of pixels which are on.
+    pub fn pixels_on(&self) -> usize {
+        self.pixels.iter().filter(|&&on| on).count()
+    }
+
+    /// Return the pattern made by moving each pixel to `position(row, column)`.
+    fn transform<F : Fn(usize, usize) -> (usize, usize)>(&self, position : F) -> Pattern {
+        let mut pattern = Pattern::new(self.size);
+        for row in 0..self.size {
+            for column in 0..self.size {
+                let (r, c) = position(row, column);
+                pattern.set(r, c, self.get(row, column));
+            }
+        }
+        pattern
+    }
+
+    /// Return the pattern rotated a quarter turn clockwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::Pattern;
+    ///
+    /// let pattern = Pattern::parse("#./..").unwrap();
+    /// assert_eq!(pattern.rotate().to_string(), ".#\n..");
+    /// ```
+    pub fn rotate(&self) -> Pattern {
+        let n = self.size;
+        self.transform(|row, column| (column, n - 1 - row))
+    }
+
+    /// Return the pattern flipped from left to right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::Pattern;
+    ///
+    /// let pattern = Pattern::parse("#../.#./...").unwrap();
+    /// assert_eq!(pattern.flip().to_string(), "..#\n.#.\n...");
+    /// ```
+    pub fn flip(&self) -> Pattern {
+        let n = self.size;
+        self.transform(|row, column| (row, n - 1 - column))
+    }
+
+    /// Return the distinct patterns made by rotating and flipping the pattern, which includes
+    /// the pattern itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::Pattern;
+    ///
+    /// assert_eq!(Pattern::parse(".#./..#/###").unwrap().symmetries().len(), 8);
+    /// assert_eq!(Pattern::parse("#./..").unwrap().symmetries().len(), 4);
+    /// ```
+    pub fn symmetries(&self) -> Vec<Pattern> {
+        let mut patterns : Vec<Pattern> = Vec::with_capacity(8);
+        for mut pattern in [self.clone(), self.flip()] {
+            for _ in 0..4 {
+                if !patterns.contains(&pattern) {
+                    patterns.push(pattern.clone());
+                }
+                pattern = pattern.rotate();
+            }
+        }
+        patterns
+    }
+
+    /// Return the blocks of `block` by `block` pixels making up the pattern, row by row, or `None`
+    /// if the pattern cannot be split into blocks of that size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::Pattern;
+    ///
+    /// let blocks = Pattern::parse("#..#/..../..../#..#").unwrap().split(2).unwrap();
+    /// assert_eq!(blocks.len(), 4);
+    /// assert!(blocks.iter().all(|b| b.to_string() == "#.\n.." || b.pixels_on() == 1));
+    /// assert_eq!(blocks[3].to_string(), "..\n.#");
+    /// assert!(Pattern::parse("#..#/..../..../#..#").unwrap().split(3).is_none());
+    /// assert!(Pattern::parse("#..#/..../..../#..#").unwrap().split(0).is_none());
+    /// ```
+    pub fn split(&self, block : usize) -> Option<Vec<Pattern>> {
+        if block == 0 || self.size % block != 0 {
+            return None;
+        }
+        let nblocks = self.size/block;
+        let mut blocks = Vec::with_capacity(nblocks*nblocks);
+        for block_row in 0..nblocks {
+            for block_column in 0..nblocks {
+                let mut pattern = Pattern::new(block);
+                for row in 0..block {
+                    for column in 0..block {
+                        let on = self.get(block_row*block + row, block_column*block + column);
+                        pattern.set(row, column, on);
+                    }
+                }
+                blocks.push(pattern);
+            }
+        }
+        Some(blocks)
+    }
+
+    /// Return the pattern made by putting `blocks` together row by row, or `None` unless there is
+    /// a non-zero square number of patterns all of the same size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::Pattern;
+    ///
+    /// let pattern = Pattern::parse("#..#/..../..../#..#").unwrap();
+    /// assert_eq!(Pattern::join(&pattern.split(2).unwrap()), Some(pattern));
+    /// assert_eq!(Pattern::join(&[]), None);
+    /// assert_eq!(Pattern::join(&[Pattern::new(2), Pattern::new(2)]), None);
+    /// assert_eq!(Pattern::join(&[Pattern::new(2), Pattern::new(2), Pattern::new(2),
+    ///                            Pattern::new(3)]), None);
+    /// ```
+    pub fn join(blocks : &[Pattern]) -> Option<Pattern> {
+        let nblocks = (1..).find(|n| n*n >= blocks.len())?;
+        let block = blocks.first()?.size;
+        if nblocks*nblocks != blocks.len() || blocks.iter().any(|b| b.size != block) {
+            return None;
+        }
+        let mut pattern = Pattern::new(nblocks*block);
+        for (idx, b) in blocks.iter().enumerate() {
+            let (block_row, block_column) = (idx/nblocks, idx % nblocks);
+            for row in 0..block {
+                for column in 0..block {
+                    let on = b.get(row, column);
+                    pattern.set(block_row*block + row, block_column*block + column, on);
+                }
+            }
+        }
+        Some(pattern)
+    }
+
+    /// Return the pattern written as in the rules, with rows separated by `/`.
+    pub fn encode(&self) -> String {
+        self.to_string().replace('\n', "/")
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        for row in 0..self.size {
+            if row > 0 {
+                writeln!(f)?;
+            }
+            for column in 0..self.size {
+                write!(f, "{}", if self.get(row, column) { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The enhancement rules, with each rule stored under every rotation and flip of its input so
+/// that patterns can be looked up directly.
+pub struct RuleBook {
+    rules : HashMap<Pattern, Pattern>
+}
+
+impl RuleBook {
+    /// Return the rule book encoded in the string, one rule per line.
+    ///
+    /// Each rule turns a 2×2 pattern into a 3×3 pattern, or a 3×3 pattern into a 4×4 pattern:
+    ///
+    /// ```text
+    /// ../.# => ##./#../...
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::Error;
+    /// use aoc17::day21::RuleBook;
+    ///
+    /// assert!(RuleBook::parse("../.# => ##./#../...").is_ok());
+    /// assert_eq!(RuleBook::parse("../.# => ##/#.").err(),
+    ///            Some(Error::Parse { day: 21, line: 1, column: 10, token: "##/#.".to_string(),
+    ///                                expected: "a 3×3 pattern" }));
+    /// ```
+    pub fn parse(s : &str) -> Result<RuleBook> {
+        let mut rules = HashMap::new();
+        for (idx, line) in s.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let error = |token : &str, expected| {
+                Error::parse(Day21::DAY, idx + 1, line, token, expected)
+            };
+            let mut tokens = line.split_whitespace();
+            let input = tokens.next().unwrap_or("");
+            let input_pattern = match Pattern::parse(input) {
+                Some(pattern) if pattern.size == 2 || pattern.size == 3 => pattern,
+                _ => return Err(error(input, "a 2×2 or 3×3 pattern"))
+            };
+            match tokens.next() {
+                Some("=>") => {},
+                other => return Err(error(other.unwrap_or(""), "`=>`"))
+            }
+            let output = tokens.next().unwrap_or("");
+            let output_pattern = match Pattern::parse(output) {
+                Some(pattern) if pattern.size == input_pattern.size + 1 => pattern,
+                _ => return Err(error(output, if input_pattern.size == 2 {
+                    "a 3×3 pattern"
+                } else {
+                    "a 4×4 pattern"
+                }))
+            };
+            if let Some(extra) = tokens.next() {
+                return Err(error(extra, "end of line"));
+            }
+            for pattern in input_pattern.symmetries() {
+                if rules.contains_key(&pattern) {
+                    return Err(error(input, "a pattern not matched by an earlier rule"));
+                }
+                rules.insert(pattern, output_pattern.clone());
+            }
+        }
+        if rules.is_empty() {
+            return Err(Error::EmptyInput { day: Day21::DAY });
+        }
+
+        Ok(RuleBook { rules })
+    }
+
+    /// Return an error naming a 2×2 or 3×3 pattern which no rule matches, if there is one.
+    pub fn check_complete(&self) -> Result<()> {
+        for &size in [2, 3].iter() {
+            for bits in 0..1usize << (size*size) {
+                let pixels = (0..size*size).map(|i| bits & (1 << i) != 0).collect();
+                let pattern = Pattern { size, pixels };
+                if !self.rules.contains_key(&pattern) {
+                    return Err(self.unmatched(&pattern));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Return the error for a `pattern` which no rule matches.
+    fn unmatched(&self, pattern : &Pattern) -> Error {
+        Error::Invalid {
+            day: Day21::DAY,
+            message: format!("no rule matches `{}`", pattern.encode())
+        }
+    }
+
+    /// Return the blocks of `block` by `block` pixels making up `pattern`.
+    fn split(pattern : &Pattern, block : usize) -> Result<Vec<Pattern>> {
+        pattern.split(block).ok_or_else(|| Error::Invalid {
+            day: Day21::DAY,
+            message: format!("a {0}×{0} pattern cannot be split into {1}×{1} blocks",
+                             pattern.size, block)
+        })
+    }
+
+    /// Return the pattern which replaces the 2×2 or 3×3 `pattern`.
+    fn rule(&self, pattern : &Pattern) -> Result<&Pattern> {
+        self.rules.get(pattern).ok_or_else(|| self.unmatched(pattern))
+    }
+
+    /// Return the result of a single iteration on `pattern`, where it is split into 2×2 blocks if
+    /// its size is even and 3×3 blocks otherwise, and each block is replaced using its rule.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::{Pattern, RuleBook, START};
+    ///
+    /// let rules = RuleBook::parse("../.# => ##./#../...
+    /// .#./..#/### => #..#/..../..../#..#").unwrap();
+    /// let pattern = rules.enhance(&Pattern::parse(START).unwrap()).unwrap();
+    /// assert_eq!(pattern.encode(), "#..#/..../..../#..#");
+    /// let pattern = rules.enhance(&pattern).unwrap();
+    /// assert_eq!(pattern.to_string(), "##.##.\n#..#..\n......\n##.##.\n#..#..\n......");
+    ///
+    /// assert!(rules.enhance(&Pattern::new(5)).is_err());
+    /// assert!(rules.enhance(&Pattern::new(0)).is_err());
+    /// ```
+    pub fn enhance(&self, pattern : &Pattern) -> Result<Pattern> {
+        let block = if pattern.size % 2 == 0 { 2 } else { 3 };
+        let blocks = RuleBook::split(pattern, block)?.iter().map(|b| self.rule(b).cloned())
+                                                     .collect::<Result<Vec<_>>>()?;
+        Pattern::join(&blocks).ok_or_else(|| Error::Invalid {
+            day: Day21::DAY,
+            message: format!("a {0}×{0} pattern has no blocks to enhance", pattern.size)
+        })
+    }
+
+    /// Return the result of `iterations` iterations on `pattern`.
+    pub fn iterate(&self, pattern : &Pattern, iterations : usize) -> Result<Pattern> {
+        let mut pattern = pattern.clone();
+        for _ in 0..iterations {
+            pattern = self.enhance(&pattern)?;
+        }
+        Ok(pattern)
+    }
+
+    /// Return the number of pixels on after `iterations` iterations on `pattern`.
+    ///
+    /// Every three iterations turn each 3×3 block into a 9×9 pattern, which is then split into
+    /// nine 3×3 blocks that no longer affect each other. Only the number of copies of each
+    /// distinct block is kept, so the full pattern is never built.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day21::{Pattern, RuleBook, START};
+    ///
+    /// let rules = RuleBook::parse("../.# => ##./#../...
+    /// .#./..#/### => #..#/..../..../#..#").unwrap();
+    /// let start = Pattern::parse(START).unwrap();
+    /// assert_eq!(rules.pixels_after(&start, 2), Ok(12));
+    /// ```
+    pub fn pixels_after(&self, pattern : &Pattern, iterations : usize) -> Result<usize> {
+        if pattern.size % 3 != 0 || pattern.size % 2 == 0 {
+            return Ok(self.iterate(pattern, iterations)?.pixels_on());
+        }
+
+        let mut counts : HashMap<Pattern, usize> = HashMap::new();
+        for block in RuleBook::split(pattern, 3)? {
+            *counts.entry(block).or_insert(0) += 1;
+        }
+        let mut remaining = iterations;
+        while remaining >= 3 {
+            let mut next = HashMap::new();
+            for (block, count) in counts {
+                for b in RuleBook::split(&self.iterate(&block, 3)?, 3)? {
+                    *next.entry(b).or_insert(0) += count;
+                }
+            }
+            counts = next;
+            remaining -= 3;
+        }
+
+        let mut pixels = 0;
+        for (block, count) in counts {
+            pixels += count*self.iterate(&block, remaining)?.pixels_on();
+        }
+        Ok(pixels)
+    }
+}
+
+/// Solver for day 21.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day21::Day21;
+/// use aoc17::solver::Solver;
+///
+/// // Rule books only need rules for the patterns that come up
+/// let rules = Day21.parse("../.# => ##./#../...
+/// .#./..#/### => #..#/..../..../#..#").unwrap();
+/// assert!(Day21.part1(&rules).is_err());
+/// ```
+pub struct Day21;
+
+impl Solver for Day21 {
+    const DAY : usize = 21;
+
+    type Input = RuleBook;
+    type Answer1 = Result<usize>;
+    type Answer2 = Result<usize>;
+
+    fn parse(&self, puzzle : &str) -> Result<RuleBook> {
+        RuleBook::parse(puzzle)
+    }
+
+    fn part1(&self, rules : &RuleBook) -> Result<usize> {
+        rules.pixels_after(&Pattern::parse(START).unwrap(), ITERATIONS1)
+    }
+
+    fn part2(&self, rules : &RuleBook) -> Result<usize> {
+        rules.pixels_after(&Pattern::parse(START).unwrap(), ITERATIONS2)
+    }
+}
@@ -0,0 +1,222 @@
+//! Solutions for day 22.
+
+use error::{Error, Result};
+use grid::{Coordinate, Direction, GridCoordinate, SparseGrid};
+use solver::Solver;
+
+/// The number of bursts in part 1.
+pub const BURSTS1 : usize = 10_000;
+
+/// The number of bursts in part 2.
+pub const BURSTS2 : usize = 10_000_000;
+
+/// The state of a node in the computing cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeState {
+    Clean,
+    Weakened,
+    Infected,
+    Flagged
+}
+
+impl NodeState {
+    /// Return the character used for the state when rendering the cluster.
+    pub fn symbol(self) -> char {
+        match self {
+            NodeState::Clean => '.',
+            NodeState::Weakened => 'W',
+            NodeState::Infected => '#',
+            NodeState::Flagged => 'F'
+        }
+    }
+}
+
+/// The rules the virus carrier follows on each burst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rules {
+    /// Clean nodes become infected and infected nodes become clean.
+    Simple,
+    /// Nodes go from clean to weakened to infected to flagged and back to clean.
+    Evolved
+}
+
+/// The cluster of nodes, where only nodes which are not clean are stored.
+pub type Cluster = SparseGrid<NodeState>;
+
+/// Return the cluster encoded in the string, where `#` is an infected node and `.` is a clean
+/// node, with the middle of the map at the origin.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::grid::Coordinate;
+/// use aoc17::day22::{parse_cluster, NodeState};
+///
+/// let cluster = parse_cluster("..#\n#..\n...").unwrap();
+/// assert_eq!(cluster.len(), 2);
+/// assert_eq!(cluster.get(Coordinate { x: 1, y: 1 }), Some(&NodeState::Infected));
+/// assert_eq!(cluster.get(Coordinate { x: -1, y: 0 }), Some(&NodeState::Infected));
+/// assert!(parse_cluster("..#\n#..").is_err());
+/// ```
+pub fn parse_cluster(s : &str) -> Result<Cluster> {
+    let lines : Vec<&str> = s.lines().filter(|line| !line.trim().is_empty()).collect();
+    let size = lines.len();
+    if size == 0 {
+        return Err(Error::EmptyInput { day: Day22::DAY });
+    }
+    if size % 2 == 0 {
+        return Err(Error::Invalid {
+            day: Day22::DAY,
+            message: format!("expected an odd number of rows, found {}", size)
+        });
+    }
+
+    let middle = (size/2) as isize;
+    let mut cluster = Cluster::new();
+    let mut row = 0;
+    for (idx, line) in s.lines().enumerate() {
+        let error = |token : &str, expected| {
+            Error::parse(Day22::DAY, idx + 1, line, token, expected)
+        };
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        if line.chars().count() != size {
+            return Err(error(&line[line.len()..], "a row as long as the map is tall"));
+        }
+        for (column, c) in line.char_indices() {
+            let position = Coordinate { x: column as isize - middle, y: middle - row };
+            match c {
+                '#' => { cluster.insert(position, NodeState::Infected); },
+                '.' => {},
+                _ => return Err(error(&line[column..column + c.len_utf8()], "`#` or `.`"))
+            }
+        }
+        row += 1;
+    }
+
+    Ok(cluster)
+}
+
+/// The virus carrier, which moves around the cluster changing the state of nodes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Carrier {
+    pub position : Coordinate,
+    pub direction : Direction
+}
+
+impl Carrier {
+    /// Return a carrier at the origin facing up.
+    pub fn new() -> Carrier {
+        Carrier { position: Coordinate::origin(), direction: Direction::Up }
+    }
+
+    /// Perform a single burst on the `cluster` following the `rules`, returning true if the
+    /// burst infected a node.
+    pub fn burst(&mut self, cluster : &mut Cluster, rules : Rules) -> bool {
+        let state = cluster.get(self.position).cloned().unwrap_or(NodeState::Clean);
+        self.direction = match state {
+            NodeState::Clean => self.direction.turn_left(),
+            NodeState::Weakened => self.direction,
+            NodeState::Infected => self.direction.turn_right(),
+            NodeState::Flagged => self.direction.reverse()
+        };
+        let next = match (rules, state) {
+            (Rules::Simple, NodeState::Clean) => NodeState::Infected,
+            (Rules::Simple, _) => NodeState::Clean,
+            (Rules::Evolved, NodeState::Clean) => NodeState::Weakened,
+            (Rules::Evolved, NodeState::Weakened) => NodeState::Infected,
+            (Rules::Evolved, NodeState::Infected) => NodeState::Flagged,
+            (Rules::Evolved, NodeState::Flagged) => NodeState::Clean
+        };
+        if next == NodeState::Clean {
+            cluster.remove(self.position);
+        } else {
+            cluster.insert(self.position, next);
+        }
+        self.position = self.position + self.direction.step();
+        next == NodeState::Infected
+    }
+}
+
+impl Default for Carrier {
+    fn default() -> Carrier {
+        Carrier::new()
+    }
+}
+
+/// Return the number of bursts which infect a node, out of `bursts` bursts by a carrier starting
+/// at the origin of the `cluster` and following the `rules`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day22::{infections, parse_cluster, Rules};
+///
+/// let cluster = parse_cluster("..#\n#..\n...").unwrap();
+/// assert_eq!(infections(&cluster, 7, Rules::Simple), 5);
+/// assert_eq!(infections(&cluster, 70, Rules::Simple), 41);
+/// assert_eq!(infections(&cluster, 10_000, Rules::Simple), 5587);
+/// assert_eq!(infections(&cluster, 100, Rules::Evolved), 26);
+/// ```
+pub fn infections(cluster : &Cluster, bursts : usize, rules : Rules) -> usize {
+    let mut cluster = cluster.clone();
+    let mut carrier = Carrier::new();
+    (0..bursts).filter(|_| carrier.burst(&mut cluster, rules)).count()
+}
+
+/// Return the `cluster` rendered with one row per line, with the `carrier`'s node shown in
+/// brackets and each other node padded with spaces, as in the puzzle.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day22::{parse_cluster, render, Carrier, Rules};
+///
+/// let mut cluster = parse_cluster("..#\n#..\n...").unwrap();
+/// let mut carrier = Carrier::new();
+/// carrier.burst(&mut cluster, Rules::Simple);
+/// assert_eq!(render(&cluster, &carrier), " .  .  # \n[#] #  . \n .  .  . ");
+/// ```
+pub fn render(cluster : &Cluster, carrier : &Carrier) -> String {
+    let (mut min, mut max) = cluster.bounds().unwrap_or((carrier.position, carrier.position));
+    // Show the carrier and at least the area of the original map around the origin
+    for p in [carrier.position, Coordinate { x: -1, y: -1 }, Coordinate { x: 1, y: 1 }].iter() {
+        min = Coordinate { x: min.x.min(p.x), y: min.y.min(p.y) };
+        max = Coordinate { x: max.x.max(p.x), y: max.y.max(p.y) };
+    }
+    let symbols = cluster.render_region(min, max, |state| state.map_or('.', |s| s.symbol()));
+    symbols.lines().zip((min.y..max.y + 1).rev()).map(|(row, y)| {
+        row.chars().zip(min.x..).map(|(symbol, x)| {
+            if (Coordinate { x, y }) == carrier.position {
+                format!("[{}]", symbol)
+            } else {
+                format!(" {} ", symbol)
+            }
+        }).collect::<String>()
+    }).collect::<Vec<_>>().join("\n")
+}
+
+/// Solver for day 22.
+pub struct Day22;
+
+impl Solver for Day22 {
+    const DAY : usize = 22;
+
+    type Input = Cluster;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Cluster> {
+        parse_cluster(puzzle)
+    }
+
+    fn part1(&self, cluster : &Cluster) -> usize {
+        infections(cluster, BURSTS1, Rules::Simple)
+    }
+
+    fn part2(&self, cluster : &Cluster) -> usize {
+        infections(cluster, BURSTS2, Rules::Evolved)
+    }
+}
@@ -0,0 +1,280 @@
+//! Solutions for day 23.
+//!
+//! The coprocessor runs on the interpreter of day 18, using its own instruction set.
+
+use day18::{self, Instruction, InstructionSet, Operand, Program, Register, Registers};
+use error::{Error, Result};
+use solver::Solver;
+
+/// An operand of an instruction in the shape of the composite-counting program.
+#[derive(Debug, Clone, Copy)]
+enum Shape {
+    /// The register with the given name.
+    Register(&'static str),
+    /// The given integer.
+    Immediate(i64),
+    /// Any integer.
+    AnyImmediate
+}
+
+impl Shape {
+    /// Return true if `operand` has this shape.
+    fn matches(self, operand : Operand) -> bool {
+        match (self, operand) {
+            (Shape::Register(name), Operand::Register(register)) => {
+                Register::named(name) == Some(register)
+            },
+            (Shape::Immediate(value), Operand::Immediate(x)) => value == x,
+            (Shape::AnyImmediate, Operand::Immediate(_)) => true,
+            _ => false
+        }
+    }
+}
+
+/// The shape of the program which counts composite numbers, as the mnemonic and operands of each
+/// instruction.
+///
+/// The program sets `b` and `c` to the ends of a range, then counts into `h` the numbers from `b`
+/// to `c` in steps of the immediate of `sub b *` near the end which are not prime. The two nested
+/// loops starting at `set f 1` try every pair of factors, so running it directly takes far too
+/// long.
+const COMPOSITE_LOOP : [(&str, Shape, Shape); 32] = [
+    ("set", Shape::Register("b"), Shape::AnyImmediate),
+    ("set", Shape::Register("c"), Shape::Register("b")),
+    ("jnz", Shape::Register("a"), Shape::Immediate(2)),
+    ("jnz", Shape::Immediate(1), Shape::Immediate(5)),
+    ("mul", Shape::Register("b"), Shape::AnyImmediate),
+    ("sub", Shape::Register("b"), Shape::AnyImmediate),
+    ("set", Shape::Register("c"), Shape::Register("b")),
+    ("sub", Shape::Register("c"), Shape::AnyImmediate),
+    ("set", Shape::Register("f"), Shape::Immediate(1)),
+    ("set", Shape::Register("d"), Shape::Immediate(2)),
+    ("set", Shape::Register("e"), Shape::Immediate(2)),
+    ("set", Shape::Register("g"), Shape::Register("d")),
+    ("mul", Shape::Register("g"), Shape::Register("e")),
+    ("sub", Shape::Register("g"), Shape::Register("b")),
+    ("jnz", Shape::Register("g"), Shape::Immediate(2)),
+    ("set", Shape::Register("f"), Shape::Immediate(0)),
+    ("sub", Shape::Register("e"), Shape::Immediate(-1)),
+    ("set", Shape::Register("g"), Shape::Register("e")),
+    ("sub", Shape::Register("g"), Shape::Register("b")),
+    ("jnz", Shape::Register("g"), Shape::Immediate(-8)),
+    ("sub", Shape::Register("d"), Shape::Immediate(-1)),
+    ("set", Shape::Register("g"), Shape::Register("d")),
+    ("sub", Shape::Register("g"), Shape::Register("b")),
+    ("jnz", Shape::Register("g"), Shape::Immediate(-13)),
+    ("jnz", Shape::Register("f"), Shape::Immediate(2)),
+    ("sub", Shape::Register("h"), Shape::Immediate(-1)),
+    ("set", Shape::Register("g"), Shape::Register("b")),
+    ("sub", Shape::Register("g"), Shape::Register("c")),
+    ("jnz", Shape::Register("g"), Shape::Immediate(2)),
+    ("jnz", Shape::Immediate(1), Shape::Immediate(3)),
+    ("sub", Shape::Register("b"), Shape::AnyImmediate),
+    ("jnz", Shape::Immediate(1), Shape::Immediate(-23))
+];
+
+/// The index of the first instruction of the outer loop, where `b` and `c` hold the range.
+const LOOP_START : usize = 8;
+
+/// The index of the instruction which steps `b` to the next number in the range.
+const LOOP_STEP : usize = 30;
+
+/// Return the coprocessor instructions encoded in the string, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day23::parse_instructions;
+///
+/// assert_eq!(parse_instructions("set b 3\nsub b 1\njnz b -1").map(|i| i.len()), Ok(3));
+/// assert!(parse_instructions("set b 3\nadd b 1").is_err());
+/// ```
+pub fn parse_instructions(s : &str) -> Result<Vec<Instruction>> {
+    day18::parse_program(s, InstructionSet::Coprocessor)
+}
+
+/// Return the program after running the `instructions` until they halt, starting with the
+/// `registers`, so that how many times each instruction was executed can be inspected.
+///
+/// Coprocessor instructions cannot fail, but instructions of day 18 which can are also accepted.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day18::Registers;
+/// use aoc17::day23::{parse_instructions, profile};
+///
+/// let instructions = parse_instructions("set b 3\nmul c b\nsub b 1\njnz b -2").unwrap();
+/// let program = profile(&instructions, Registers::new()).unwrap();
+/// assert_eq!(program.executions(), &[1, 3, 3, 3]);
+/// assert_eq!(program.executions_of("mul"), 3);
+/// ```
+pub fn profile(instructions : &[Instruction], registers : Registers) -> Result<Program<'_>> {
+    let mut program = Program::with_registers(instructions, registers);
+    // Without `snd` and `rcv`, the only way for the coprocessor to stop is to halt
+    program.run()?;
+    Ok(program)
+}
+
+/// The range of numbers checked by the composite-counting program.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CompositeLoop {
+    /// The first number checked.
+    pub start : i64,
+    /// The last number checked.
+    pub end : i64,
+    /// The difference between consecutive numbers checked.
+    pub step : i64
+}
+
+impl CompositeLoop {
+    /// Return the number of composite numbers in the range, which is the final value of `h`.
+    ///
+    /// Returns `None` if the range never ends, because its step is not positive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::day23::CompositeLoop;
+    ///
+    /// assert_eq!(CompositeLoop { start: 4, end: 10, step: 2 }.composites(), Some(4));
+    /// assert_eq!(CompositeLoop { start: 5, end: 11, step: 3 }.composites(), Some(1));
+    /// assert_eq!(CompositeLoop { start: 5, end: 11, step: 0 }.composites(), None);
+    /// assert_eq!(CompositeLoop { start: 12, end: 11, step: 0 }.composites(), Some(0));
+    /// assert_eq!(CompositeLoop { start: i64::MAX - 1, end: i64::MAX, step: 1 }.composites(),
+    ///            Some(2));
+    /// ```
+    pub fn composites(&self) -> Option<usize> {
+        if self.start > self.end {
+            return Some(0);
+        }
+        if self.step <= 0 {
+            return None;
+        }
+        // Compare `d` with `n/d` rather than `d*d` with `n`, which could overflow
+        let is_composite = |n : i64| n > 3 && (2..).take_while(|&d| d <= n/d).any(|d| n % d == 0);
+        let mut count = 0;
+        let mut n = self.start;
+        loop {
+            if is_composite(n) {
+                count += 1;
+            }
+            // Stepping past `i64::MAX` also steps past the end
+            match n.checked_add(self.step) {
+                Some(next) if next <= self.end => n = next,
+                _ => return Some(count)
+            }
+        }
+    }
+}
+
+/// Return true if the `instruction` has the `mnemonic` and operands of the given shapes.
+fn matches(instruction : &Instruction, &(mnemonic, x, y) : &(&str, Shape, Shape)) -> bool {
+    let operands = instruction.operands();
+    instruction.mnemonic() == mnemonic && operands.len() == 2 && x.matches(operands[0]) &&
+        y.matches(operands[1])
+}
+
+/// Return the range checked by the `instructions` when `a` starts at 1, if they are the
+/// composite-counting program.
+///
+/// The start of the program is run to find the range, so any integers may be used to set it up.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day23::{composite_loop, parse_instructions, CompositeLoop};
+///
+/// let instructions = parse_instructions("set b 57
+/// set c b
+/// jnz a 2
+/// jnz 1 5
+/// mul b 100
+/// sub b -100000
+/// set c b
+/// sub c -17000
+/// set f 1
+/// set d 2
+/// set e 2
+/// set g d
+/// mul g e
+/// sub g b
+/// jnz g 2
+/// set f 0
+/// sub e -1
+/// set g e
+/// sub g b
+/// jnz g -8
+/// sub d -1
+/// set g d
+/// sub g b
+/// jnz g -13
+/// jnz f 2
+/// sub h -1
+/// set g b
+/// sub g c
+/// jnz g 2
+/// jnz 1 3
+/// sub b -17
+/// jnz 1 -23").unwrap();
+/// assert_eq!(composite_loop(&instructions),
+///            Some(CompositeLoop { start: 105700, end: 122700, step: 17 }));
+/// assert_eq!(composite_loop(&instructions[1..]), None);
+/// ```
+pub fn composite_loop(instructions : &[Instruction]) -> Option<CompositeLoop> {
+    if instructions.len() != COMPOSITE_LOOP.len() ||
+       !instructions.iter().zip(COMPOSITE_LOOP.iter()).all(|(i, p)| matches(i, p)) {
+        return None;
+    }
+
+    let register = |name| Register::named(name).unwrap();
+    let mut registers = Registers::new();
+    registers.set(register("a"), 1);
+    let mut program = Program::with_registers(instructions, registers);
+    program.run_to(LOOP_START).ok()?;
+    let registers = program.registers();
+    let (start, end) = (registers.get(register("b")), registers.get(register("c")));
+    // The step is subtracted from `b`, so its immediate is negative
+    let step = match instructions[LOOP_STEP].operands()[1] {
+        Operand::Immediate(value) => value.checked_neg()?,
+        Operand::Register(_) => return None
+    };
+
+    // The loops counting factors up to `b` never end if `b` starts below 3, and the outer loop
+    // never ends if `b` steps past `c`
+    if program.counter() != LOOP_START || start < 3 || step <= 0 || start > end ||
+       (end - start) % step != 0 {
+        return None;
+    }
+    Some(CompositeLoop { start, end, step })
+}
+
+/// Solver for day 23.
+pub struct Day23;
+
+impl Solver for Day23 {
+    const DAY : usize = 23;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = Result<usize>;
+    type Answer2 = Result<usize>;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<Instruction>> {
+        parse_instructions(puzzle)
+    }
+
+    fn part1(&self, instructions : &Vec<Instruction>) -> Result<usize> {
+        profile(instructions, Registers::new()).map(|program| program.executions_of("mul"))
+    }
+
+    fn part2(&self, instructions : &Vec<Instruction>) -> Result<usize> {
+        let range = composite_loop(instructions).ok_or_else(|| Error::Invalid {
+            day: Self::DAY,
+            message: "the program is not the composite-counting loop".to_string()
+        })?;
+        range.composites().ok_or_else(|| Error::Invalid {
+            day: Self::DAY,
+            message: "the composite-counting loop never ends".to_string()
+        })
+    }
+}
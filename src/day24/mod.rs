@@ -0,0 +1,264 @@
+//! Solutions for day 24.
+
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// A component with a port at each end, given by the number of pins on each port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Component(pub usize, pub usize);
+
+impl Component {
+    /// Return the strength of the component, which is the number of pins on both of its ports.
+    ///
+    /// Returns `None` if the strength is too large for a `usize`.
+    pub fn strength(&self) -> Option<usize> {
+        self.0.checked_add(self.1)
+    }
+}
+
+impl Display for Component {
+    fn fmt(&self, f : &mut Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.0, self.1)
+    }
+}
+
+/// Return the components encoded in the string, one per line, as `a/b`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day24::{parse_components, Component};
+///
+/// assert_eq!(parse_components("0/2\n2/10"), Ok(vec![Component(0, 2), Component(2, 10)]));
+/// assert_eq!(parse_components("0/2\n2-10").err(),
+///            Some(Error::Parse { day: 24, line: 2, column: 1, token: "2-10".to_string(),
+///                                expected: "two ports separated by '/'" }));
+/// assert!(parse_components("0/2\n2/x").is_err());
+/// ```
+pub fn parse_components(s : &str) -> Result<Vec<Component>> {
+    let mut components = Vec::new();
+    for (idx, line) in s.lines().enumerate() {
+        let error = |token : &str, expected| {
+            Error::parse(Day24::DAY, idx + 1, line, token, expected)
+        };
+        let token = line.trim();
+        if token.is_empty() {
+            continue;
+        }
+        let mut ports = token.splitn(2, '/');
+        let (a, b) = match (ports.next(), ports.next()) {
+            (Some(a), Some(b)) => (a, b),
+            _ => return Err(error(token, "two ports separated by '/'"))
+        };
+        let parse_port = |port : &str| port.parse().map_err(|_| error(port, "a number of pins"));
+        components.push(Component(parse_port(a)?, parse_port(b)?));
+    }
+    if components.is_empty() {
+        return Err(Error::EmptyInput { day: Day24::DAY });
+    }
+    Ok(components)
+}
+
+/// A bridge of components, starting from a port with zero pins, where each component is joined to
+/// the next by ports with the same number of pins.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bridge {
+    components : Vec<Component>
+}
+
+impl Bridge {
+    /// Return the components of the bridge in order, as they were listed.
+    pub fn components(&self) -> &[Component] {
+        &self.components
+    }
+
+    /// Return the number of components in the bridge.
+    pub fn len(&self) -> usize {
+        self.components.len()
+    }
+
+    /// Return true if the bridge has no components.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty()
+    }
+
+    /// Return the strength of the bridge, which is the sum of the strengths of its components.
+    pub fn strength(&self) -> usize {
+        // Bridges are only built by `best_bridges`, which checked that the strengths of all the
+        // components add up without overflowing
+        self.components.iter().map(|c| c.0 + c.1).sum()
+    }
+}
+
+/// Bridges are shown as their components joined by `--`.
+impl Display for Bridge {
+    fn fmt(&self, f : &mut Formatter) -> fmt::Result {
+        let components = self.components.iter().map(|c| c.to_string()).collect::<Vec<_>>();
+        write!(f, "{}", components.join("--"))
+    }
+}
+
+/// The set of components used by a bridge, with one bit for each component.
+struct Used {
+    words : Vec<u64>
+}
+
+impl Used {
+    /// Return a set with room for `n` components, none of which are used.
+    fn new(n : usize) -> Used {
+        Used { words: vec![0; n.div_ceil(64)] }
+    }
+
+    /// Return true if component `idx` is used.
+    fn contains(&self, idx : usize) -> bool {
+        self.words[idx/64] & (1 << (idx % 64)) != 0
+    }
+
+    /// Mark component `idx` as used or unused.
+    fn toggle(&mut self, idx : usize) {
+        self.words[idx/64] ^= 1 << (idx % 64);
+    }
+}
+
+/// The best bridges found by `best_bridges`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BestBridges {
+    /// The strongest bridge.
+    pub strongest : Bridge,
+    /// The strongest of the longest bridges.
+    pub longest : Bridge
+}
+
+/// The state of the search for the best bridges.
+struct Search<'a> {
+    components : &'a [Component],
+    /// The indices of the components with each number of pins on either port.
+    by_port : HashMap<usize, Vec<usize>>,
+    used : Used,
+    /// The components of the bridge being built.
+    bridge : Vec<Component>,
+    strongest : (usize, Vec<Component>),
+    longest : (usize, usize, Vec<Component>)
+}
+
+impl<'a> Search<'a> {
+    /// Record the bridge being built, of the given `strength`, if it is the best so far, then try
+    /// every unused component which fits the free `port` at the end of it.
+    fn extend(&mut self, port : usize, strength : usize) {
+        if strength > self.strongest.0 {
+            self.strongest = (strength, self.bridge.clone());
+        }
+        if (self.bridge.len(), strength) > (self.longest.0, self.longest.1) {
+            self.longest = (self.bridge.len(), strength, self.bridge.clone());
+        }
+
+        // Candidates are looked up by index, as `self` is borrowed mutably while trying each one
+        let ncandidates = self.by_port.get(&port).map_or(0, Vec::len);
+        for i in 0..ncandidates {
+            let idx = self.by_port[&port][i];
+            if self.used.contains(idx) {
+                continue;
+            }
+            let component = self.components[idx];
+            let free = if component.0 == port { component.1 } else { component.0 };
+
+            self.used.toggle(idx);
+            self.bridge.push(component);
+            self.extend(free, strength + component.0 + component.1);
+            self.bridge.pop();
+            self.used.toggle(idx);
+        }
+    }
+}
+
+/// Return the strongest bridge which can be built from `components`, and the strongest of the
+/// longest bridges, each using every component at most once.
+///
+/// Every bridge is built by backtracking, and when several bridges are equally good the first
+/// one found is returned. Fails if the strengths of all the components together are too large
+/// for a `usize`, as the strength of a bridge might then be.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day24::{best_bridges, parse_components};
+///
+/// let components = parse_components("0/2
+/// 2/2
+/// 2/3
+/// 3/4
+/// 3/5
+/// 0/1
+/// 10/1
+/// 9/10").unwrap();
+/// let bridges = best_bridges(&components).unwrap();
+/// assert_eq!(bridges.strongest.strength(), 31);
+/// assert_eq!(bridges.strongest.to_string(), "0/1--10/1--9/10");
+/// assert_eq!(bridges.longest.strength(), 19);
+/// assert_eq!(bridges.longest.to_string(), "0/2--2/2--2/3--3/5");
+///
+/// let components = parse_components("0/4000000000\n4000000000/18446744073709551615").unwrap();
+/// assert!(best_bridges(&components).is_err());
+/// ```
+pub fn best_bridges(components : &[Component]) -> Result<BestBridges> {
+    // No bridge is stronger than all the components together, so checking their total here
+    // leaves the search free to add up strengths without overflowing
+    let total = components.iter().try_fold(0usize, |sum, c| c.strength()?.checked_add(sum));
+    if total.is_none() {
+        return Err(Error::Invalid {
+            day: Day24::DAY,
+            message: "the components are too strong for the strength of a bridge to be counted"
+                         .to_string()
+        });
+    }
+
+    let mut by_port : HashMap<usize, Vec<usize>> = HashMap::new();
+    for (idx, component) in components.iter().enumerate() {
+        by_port.entry(component.0).or_default().push(idx);
+        if component.1 != component.0 {
+            by_port.entry(component.1).or_default().push(idx);
+        }
+    }
+
+    let mut search = Search {
+        components,
+        by_port,
+        used: Used::new(components.len()),
+        bridge: Vec::new(),
+        strongest: (0, Vec::new()),
+        longest: (0, 0, Vec::new())
+    };
+    search.extend(0, 0);
+    Ok(BestBridges {
+        strongest: Bridge { components: search.strongest.1 },
+        longest: Bridge { components: search.longest.2 }
+    })
+}
+
+/// Solver for day 24.
+pub struct Day24;
+
+impl Solver for Day24 {
+    const DAY : usize = 24;
+
+    type Input = BestBridges;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<BestBridges> {
+        // Both parts need the same search, so it is run once here
+        best_bridges(&parse_components(puzzle)?)
+    }
+
+    fn part1(&self, bridges : &BestBridges) -> usize {
+        bridges.strongest.strength()
+    }
+
+    fn part2(&self, bridges : &BestBridges) -> usize {
+        bridges.longest.strength()
+    }
+}
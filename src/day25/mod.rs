@@ -0,0 +1,370 @@
+//! Solutions for day 25.
+
+use std::str::Lines;
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// The direction the cursor of a Turing machine moves along its tape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Left,
+    Right
+}
+
+/// What a Turing machine does in a state, given the value under its cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Action {
+    /// The value written under the cursor.
+    pub write : bool,
+    /// The direction the cursor moves after writing.
+    pub movement : Move,
+    /// The index of the state to continue with.
+    pub next : usize
+}
+
+/// A state of a Turing machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct State {
+    /// The name given to the state in the blueprint.
+    pub name : String,
+    /// The actions taken when the value under the cursor is 0 and 1 respectively.
+    pub actions : [Action; 2]
+}
+
+/// The blueprint of a Turing machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Blueprint {
+    /// The index of the state the machine begins in.
+    pub start : usize,
+    /// The number of steps after which the diagnostic checksum is taken.
+    pub steps : usize,
+    /// The states of the machine, in the order they are described.
+    pub states : Vec<State>
+}
+
+/// The lines of a blueprint which are not blank, along with their line numbers.
+struct Sentences<'a> {
+    lines : Lines<'a>,
+    lineno : usize
+}
+
+impl<'a> Sentences<'a> {
+    /// Return true if every line left is blank.
+    fn is_empty(&self) -> bool {
+        self.lines.clone().all(|line| line.trim().is_empty())
+    }
+
+    /// Return the line number and text of the next line which is not blank, and the part of it
+    /// between `prefix` and `suffix`.
+    fn expect(&mut self, prefix : &str, suffix : &str, expected : &'static str)
+            -> Result<(usize, &'a str, &'a str)> {
+        let line = loop {
+            self.lineno += 1;
+            match self.lines.next() {
+                Some(line) if line.trim().is_empty() => continue,
+                Some(line) => break line,
+                None => return Err(Error::parse(Day25::DAY, self.lineno, "", "", expected))
+            }
+        };
+        let error = |token : &str| Error::parse(Day25::DAY, self.lineno, line, token, expected);
+        let sentence = line.trim();
+        if !sentence.starts_with(prefix) {
+            return Err(error(sentence));
+        }
+        let rest = &sentence[prefix.len()..];
+        match rest.strip_suffix(suffix) {
+            Some(token) if !token.is_empty() => Ok((self.lineno, line, token)),
+            _ => Err(error(rest))
+        }
+    }
+}
+
+/// Return a parse error for `token` found on the `lineno`th line of a blueprint, `line`.
+fn error(lineno : usize, line : &str, token : &str, expected : &'static str) -> Error {
+    Error::parse(Day25::DAY, lineno, line, token, expected)
+}
+
+/// Return the value written as `token`, which must be 0 or 1.
+fn parse_value(token : &str) -> Option<bool> {
+    match token {
+        "0" => Some(false),
+        "1" => Some(true),
+        _ => None
+    }
+}
+
+/// Return the Turing machine blueprint described by the string.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day25::{parse_blueprint, Move};
+///
+/// let blueprint = parse_blueprint("Begin in state A.
+/// Perform a diagnostic checksum after 6 steps.
+///
+/// In state A:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the right.
+///     - Continue with state B.
+///   If the current value is 1:
+///     - Write the value 0.
+///     - Move one slot to the left.
+///     - Continue with state B.
+///
+/// In state B:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the left.
+///     - Continue with state A.
+///   If the current value is 1:
+///     - Write the value 1.
+///     - Move one slot to the right.
+///     - Continue with state A.").unwrap();
+/// assert_eq!((blueprint.start, blueprint.steps, blueprint.states.len()), (0, 6, 2));
+/// assert_eq!(blueprint.states[1].name, "B");
+/// assert_eq!(blueprint.states[0].actions[1].movement, Move::Left);
+///
+/// assert_eq!(parse_blueprint("Begin in state A.
+/// Perform a diagnostic checksum after 6 steps.
+///
+/// In state A:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the up.").err(),
+///            Some(Error::Parse { day: 25, line: 7, column: 28, token: "up.".to_string(),
+///                                expected: "`- Move one slot to the left.` or `right.`" }));
+///
+/// let blueprint = "Begin in state B.\nPerform a diagnostic checksum after 6 steps.";
+/// assert_eq!(parse_blueprint(blueprint).err(),
+///            Some(Error::Parse { day: 25, line: 1, column: 16, token: "B".to_string(),
+///                                expected: "a described state" }));
+/// ```
+pub fn parse_blueprint(s : &str) -> Result<Blueprint> {
+    if s.trim().is_empty() {
+        return Err(Error::EmptyInput { day: Day25::DAY });
+    }
+    let mut sentences = Sentences { lines: s.lines(), lineno: 0 };
+    let start = sentences.expect("Begin in state ", ".", "`Begin in state X.`")?;
+    let expected = "`Perform a diagnostic checksum after N steps.`";
+    let (lineno, line, steps) = sentences.expect("Perform a diagnostic checksum after ", " steps.",
+                                                  expected)?;
+    let steps = steps.parse().map_err(|_| error(lineno, line, steps, "a number of steps"))?;
+
+    // States may continue with states described later, so names are only resolved at the end
+    let mut names = Vec::new();
+    let mut references = vec![start];
+    let mut states = Vec::new();
+    while !sentences.is_empty() {
+        let (lineno, line, name) = sentences.expect("In state ", ":", "`In state X:`")?;
+        if names.contains(&name) {
+            return Err(error(lineno, line, name, "a state not already described"));
+        }
+        names.push(name);
+
+        let mut actions = Vec::new();
+        for &(value, expected) in &[("0", "`If the current value is 0:`"),
+                                    ("1", "`If the current value is 1:`")] {
+            let (lineno, line, token) = sentences.expect("If the current value is ", ":",
+                                                         expected)?;
+            if token != value {
+                return Err(error(lineno, line, token, expected));
+            }
+
+            let expected = "`- Write the value 0.` or `1.`";
+            let (lineno, line, token) = sentences.expect("- Write the value ", ".", expected)?;
+            let write = parse_value(token).ok_or_else(|| error(lineno, line, token, expected))?;
+
+            let expected = "`- Move one slot to the left.` or `right.`";
+            let (lineno, line, token) = sentences.expect("- Move one slot to the ", "", expected)?;
+            let movement = match token {
+                "left." => Move::Left,
+                "right." => Move::Right,
+                _ => return Err(error(lineno, line, token, expected))
+            };
+
+            let expected = "`- Continue with state X.`";
+            let next = sentences.expect("- Continue with state ", ".", expected)?;
+            actions.push(Action { write, movement, next: references.len() });
+            references.push(next);
+        }
+        states.push((name, actions));
+    }
+
+    // Every reference to a state, starting with the initial state, must name a described state
+    let mut indices = Vec::with_capacity(references.len());
+    for (lineno, line, name) in references {
+        match names.iter().position(|&n| n == name) {
+            Some(idx) => indices.push(idx),
+            None => return Err(error(lineno, line, name, "a described state"))
+        }
+    }
+    let states = states.into_iter().map(|(name, actions)| {
+        let action = |idx : usize| Action { next: indices[actions[idx].next], ..actions[idx] };
+        State { name: name.to_string(), actions: [action(0), action(1)] }
+    }).collect();
+
+    Ok(Blueprint { start: indices[0], steps, states })
+}
+
+/// A tape of values which grows in both directions as the cursor moves along it.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day25::Tape;
+///
+/// let mut tape = Tape::new();
+/// tape.set(-3, true);
+/// tape.set(2, true);
+/// assert!(tape.get(-3));
+/// assert!(!tape.get(-2));
+/// assert!(!tape.get(100));
+/// assert_eq!(tape.checksum(), 2);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Tape {
+    /// The values at positions 0, 1, 2, ...
+    right : Vec<bool>,
+    /// The values at positions -1, -2, -3, ...
+    left : Vec<bool>
+}
+
+impl Tape {
+    /// Return a tape holding 0 at every position.
+    pub fn new() -> Tape {
+        Tape::default()
+    }
+
+    /// Return the value at `position`.
+    pub fn get(&self, position : isize) -> bool {
+        let (half, idx) = if position >= 0 {
+            (&self.right, position as usize)
+        } else {
+            (&self.left, (-position - 1) as usize)
+        };
+        half.get(idx).cloned().unwrap_or(false)
+    }
+
+    /// Set the value at `position`, growing the tape if needed.
+    pub fn set(&mut self, position : isize, value : bool) {
+        let (half, idx) = if position >= 0 {
+            (&mut self.right, position as usize)
+        } else {
+            (&mut self.left, (-position - 1) as usize)
+        };
+        if idx >= half.len() {
+            half.resize(idx + 1, false);
+        }
+        half[idx] = value;
+    }
+
+    /// Return the diagnostic checksum of the tape, which is the number of positions holding 1.
+    pub fn checksum(&self) -> usize {
+        self.left.iter().chain(self.right.iter()).filter(|&&value| value).count()
+    }
+}
+
+/// Return the tape after running the Turing machine of the `blueprint` for `steps` steps, starting
+/// on a tape holding 0 at every position.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day25::{run, Action, Blueprint, Move, State};
+///
+/// let state = State { name: "A".to_string(), actions: [
+///     Action { write: true, movement: Move::Left, next: 0 },
+///     Action { write: false, movement: Move::Left, next: 0 }
+/// ] };
+/// let blueprint = Blueprint { start: 0, steps: 3, states: vec![state] };
+/// let tape = run(&blueprint, 3);
+/// assert_eq!((tape.get(0), tape.get(-1), tape.get(-2)), (true, true, true));
+/// ```
+pub fn run(blueprint : &Blueprint, steps : usize) -> Tape {
+    let mut tape = Tape::new();
+    let mut position = 0;
+    let mut state = blueprint.start;
+    for _ in 0..steps {
+        let action = blueprint.states[state].actions[tape.get(position) as usize];
+        tape.set(position, action.write);
+        position += match action.movement {
+            Move::Left => -1,
+            Move::Right => 1
+        };
+        state = action.next;
+    }
+    tape
+}
+
+/// Return the diagnostic checksum of the Turing machine of the `blueprint`, after running it for
+/// the number of steps the blueprint gives.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day25::{diagnostic_checksum, parse_blueprint};
+///
+/// let blueprint = parse_blueprint("Begin in state A.
+/// Perform a diagnostic checksum after 6 steps.
+///
+/// In state A:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the right.
+///     - Continue with state B.
+///   If the current value is 1:
+///     - Write the value 0.
+///     - Move one slot to the left.
+///     - Continue with state B.
+///
+/// In state B:
+///   If the current value is 0:
+///     - Write the value 1.
+///     - Move one slot to the left.
+///     - Continue with state A.
+///   If the current value is 1:
+///     - Write the value 1.
+///     - Move one slot to the right.
+///     - Continue with state A.").unwrap();
+/// assert_eq!(diagnostic_checksum(&blueprint), 3);
+/// ```
+pub fn diagnostic_checksum(blueprint : &Blueprint) -> usize {
+    run(blueprint, blueprint.steps).checksum()
+}
+
+/// Solver for day 25, which only has one puzzle.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day25::Day25;
+/// use aoc17::solver::Solution;
+///
+/// assert_eq!(Day25.parts(), 1);
+/// ```
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY : usize = 25;
+    const PARTS : usize = 1;
+
+    type Input = Blueprint;
+    type Answer1 = usize;
+    type Answer2 = Result<usize>;
+
+    fn parse(&self, puzzle : &str) -> Result<Blueprint> {
+        parse_blueprint(puzzle)
+    }
+
+    fn part1(&self, blueprint : &Blueprint) -> usize {
+        diagnostic_checksum(blueprint)
+    }
+
+    fn part2(&self, _blueprint : &Blueprint) -> Result<usize> {
+        Err(Error::Invalid { day: Self::DAY, message: "there is no second puzzle".to_string() })
+    }
+}
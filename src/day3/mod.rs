@@ -1,24 +1,16 @@
 //! Solutions to day 3.
 
 use std::collections::HashMap;
-use std::iter;
-use std::ops::Add;
 
-#[derive(Debug,Hash,PartialEq,Eq,Copy,Clone)]
-struct Coordinate {
-    x : isize,
-    y : isize
-}
+use error::{Error, Result};
+use grid::{Direction, GridCoordinate};
+use solver::Solver;
 
-impl<'a, 'b> Add<&'b Coordinate> for &'a Coordinate {
-    type Output = Coordinate;
+pub use grid::Coordinate;
 
-    fn add(self, other : &'b Coordinate) -> Coordinate {
-        Coordinate {
-            x: self.x + other.x,
-            y: self.y + other.y
-        }
-    }
+/// Return the error for a number `num` which is not in the spiral, since it is not positive.
+fn not_in_spiral(num : isize) -> Error {
+    Error::Invalid { day: Day3::DAY, message: format!("{} is not a positive number", num) }
 }
 
 /// Return the (x, y) coordinates of the number in a spiral grid.
@@ -53,19 +45,25 @@ impl<'a, 'b> Add<&'b Coordinate> for &'a Coordinate {
 /// ```
 /// use aoc17::day3::spiral_coordinates;
 ///
-/// assert_eq!(spiral_coordinates(1), (0, 0));
-/// assert_eq!(spiral_coordinates(12), (2, 1));
-/// assert_eq!(spiral_coordinates(21), (-2, -2));
+/// assert_eq!(spiral_coordinates(1), Ok((0, 0)));
+/// assert_eq!(spiral_coordinates(12), Ok((2, 1)));
+/// assert_eq!(spiral_coordinates(21), Ok((-2, -2)));
+/// assert!(spiral_coordinates(0).is_err());
 /// ```
-pub fn spiral_coordinates(num : isize) -> (isize, isize) {
-    assert!(num > 0);
+pub fn spiral_coordinates(num : isize) -> Result<(isize, isize)> {
+    if num <= 0 {
+        return Err(not_in_spiral(num));
+    }
 
     let mut n : isize = 1;
     let mut bottom_right : isize;
     let mut ndigits_per_side : isize;
     loop {
         ndigits_per_side = 2*n - 1;
-        bottom_right = ndigits_per_side*ndigits_per_side;
+        bottom_right = ndigits_per_side.checked_mul(ndigits_per_side).ok_or_else(|| Error::Invalid {
+            day: Day3::DAY,
+            message: format!("{} is too far out in the spiral", num)
+        })?;
         if num <= bottom_right {
             break;
         }
@@ -78,27 +76,27 @@ pub fn spiral_coordinates(num : isize) -> (isize, isize) {
 
     if (bottom_left < num) && (num <= bottom_right) {
         coord.x -= bottom_right - num;
-        return (coord.x, coord.y);
+        return Ok((coord.x, coord.y));
     } else {
         coord.x -= bottom_right - bottom_left;
     }
 
     if (top_left < num) && (num <= bottom_left) {
         coord.y += bottom_left - num;
-        return (coord.x, coord.y);
+        return Ok((coord.x, coord.y));
     } else {
         coord.y += bottom_left - top_left;
     }
 
     if (top_right < num) && (num <= top_left) {
         coord.x += top_left - num;
-        return (coord.x, coord.y);
+        return Ok((coord.x, coord.y));
     } else {
         coord.x += top_left - top_right;
         coord.y -= top_right - num;
     }
 
-    (coord.x, coord.y)
+    Ok((coord.x, coord.y))
 }
 
 
@@ -122,14 +120,17 @@ pub fn spiral_coordinates(num : isize) -> (isize, isize) {
 /// ```
 /// use aoc17::day3::first_spiral_number_larger_than;
 ///
-/// assert_eq!(first_spiral_number_larger_than(6), 10);
-/// // assert_eq!(first_spiral_number_larger_than(750), 806);
+/// assert_eq!(first_spiral_number_larger_than(6), Ok(10));
+/// assert_eq!(first_spiral_number_larger_than(750), Ok(806));
+/// assert!(first_spiral_number_larger_than(-1).is_err());
+/// assert!(first_spiral_number_larger_than(isize::MAX).is_err());
 /// ```
-pub fn first_spiral_number_larger_than(num : isize) -> isize {
-    assert!(num > 0);
+pub fn first_spiral_number_larger_than(num : isize) -> Result<isize> {
+    if num <= 0 {
+        return Err(not_in_spiral(num));
+    }
 
     let mut d = HashMap::new();
-    let mut n = 1;
 
     let surrounding_coordinates = [
         // Top row
@@ -145,34 +146,61 @@ pub fn first_spiral_number_larger_than(num : isize) -> isize {
         Coordinate { x: 1, y: -1 }
     ];
 
-    // Do the first ring by hand
+    // Do the first square by hand
     d.insert(Coordinate { x: 0, y: 0 }, 1);
-    n += 1;
-    if num < 1 {
-        return 1;
-    }
 
+    // Walk the spiral anticlockwise, turning left whenever the square to the left is empty. The
+    // first step turns from down to right
     let mut current_coordinate = Coordinate { x: 0, y: 0 };
+    let mut direction = Direction::Down;
     loop {
-        // Loop around the current ring
-        let moves = iter::repeat((1, 0)).take(1)
-             .chain(iter::repeat((0, 1)).take(2*n - 3))
-             .chain(iter::repeat((-1, 0)).take(2*n - 2))
-             .chain(iter::repeat((0, -1)).take(2*n - 2))
-             .chain(iter::repeat((1, 0)).take(2*n - 2));
-        for (x, y) in moves {
-            let mv = Coordinate { x, y };
-            current_coordinate = &current_coordinate + &mv;
-            let mut val = 0;
-            for neighbour_offset in surrounding_coordinates.iter() {
-                let neighbour = &current_coordinate + neighbour_offset;
-                val += d.get(&neighbour).unwrap_or(&0);
-            }
-            d.insert(current_coordinate.clone(), val);
-            if num < val {
-                return val;
-            }
+        let left = direction.turn_left();
+        if !d.contains_key(&(current_coordinate + left.step())) {
+            direction = left;
         }
-        n += 1;
+        current_coordinate = current_coordinate + direction.step();
+        let mut val : isize = 0;
+        for neighbour_offset in surrounding_coordinates.iter() {
+            let neighbour = current_coordinate + *neighbour_offset;
+            val = val.checked_add(*d.get(&neighbour).unwrap_or(&0)).ok_or_else(|| Error::Invalid {
+                day: Day3::DAY,
+                message: format!("the spiral overflows before passing {}", num)
+            })?;
+        }
+        d.insert(current_coordinate, val);
+        if num < val {
+            return Ok(val);
+        }
+    }
+}
+
+/// Solver for day 3.
+pub struct Day3;
+
+impl Solver for Day3 {
+    const DAY : usize = 3;
+
+    type Input = isize;
+    type Answer1 = Result<usize>;
+    type Answer2 = Result<isize>;
+
+    fn parse(&self, puzzle : &str) -> Result<isize> {
+        let token = puzzle.trim();
+        if token.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+        match token.parse() {
+            Ok(num) if num > 0 => Ok(num),
+            _ => Err(Error::parse(Self::DAY, 1, puzzle, token, "a positive integer"))
+        }
+    }
+
+    fn part1(&self, num : &isize) -> Result<usize> {
+        let (x, y) = spiral_coordinates(*num)?;
+        Ok(Coordinate { x, y }.distance(&Coordinate::origin()))
+    }
+
+    fn part2(&self, num : &isize) -> Result<isize> {
+        first_spiral_number_larger_than(*num)
     }
 }
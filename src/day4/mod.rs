@@ -1,5 +1,10 @@
+//! Solutions for day 4.
+
 use std::collections::HashMap;
 
+use error::{Error, Result};
+use solver::Solver;
+
 /// Return true if the input has unique substrings.
 ///
 /// The algorithm works by constructing a hash table from a substring to a boolean. If the
@@ -17,6 +22,7 @@ use std::collections::HashMap;
 /// assert_eq!(has_unique_substrings("aa bb cc dd aa"), false);
 /// assert_eq!(has_unique_substrings("aa bb cc dd aaa"), true);
 /// ```
+#[allow(clippy::bool_comparison)]
 pub fn has_unique_substrings(s : &str) -> bool {
     let subs : Vec<&str> = s.split(" ").collect();
     let mut map : HashMap<&str, bool> = HashMap::new();
@@ -50,6 +56,7 @@ pub fn has_unique_substrings(s : &str) -> bool {
 /// assert_eq!(has_no_anagram_substrings("iiii oiii ooii oooi oooo"), true);
 /// assert_eq!(has_no_anagram_substrings("oiii ioii iioi iiio"), false);
 /// ```
+#[allow(clippy::bool_comparison)]
 pub fn has_no_anagram_substrings(s : &str) -> bool {
     let subs : Vec<&str> = s.split(" ").collect();
     let mut map : HashMap<String, bool> = HashMap::new();
@@ -69,3 +76,31 @@ pub fn has_no_anagram_substrings(s : &str) -> bool {
 
     true
 }
+
+/// Solver for day 4.
+pub struct Day4;
+
+impl Solver for Day4 {
+    const DAY : usize = 4;
+
+    type Input = Vec<String>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<String>> {
+        let passphrases : Vec<String> = puzzle.lines().map(|line| line.to_string()).collect();
+        if passphrases.is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+
+        Ok(passphrases)
+    }
+
+    fn part1(&self, passphrases : &Vec<String>) -> usize {
+        passphrases.iter().filter(|line| has_unique_substrings(line)).count()
+    }
+
+    fn part2(&self, passphrases : &Vec<String>) -> usize {
+        passphrases.iter().filter(|line| has_no_anagram_substrings(line)).count()
+    }
+}
@@ -1,5 +1,8 @@
 //! Solutions for day 5
 
+use error::{Error, Result};
+use solver::Solver;
+
 /// Return the number of jumps it takes to exit the instruction set encoded in the string
 ///
 /// The `offset_rule` defines how the instruction that was just ran should be mutated for the next
@@ -16,7 +19,7 @@
 /// 3
 /// 0
 /// 1
-/// -3", |jump| 1), 5);
+/// -3", |_| 1), Ok(5));
 /// ```
 ///
 /// The `offset_rule` increments the just-run instruction by 1 if its value was less than 3, else
@@ -30,10 +33,48 @@
 /// 3
 /// 0
 /// 1
-/// -3", offset_rule), 10);
+/// -3", offset_rule), Ok(10));
+/// ```
+///
+/// Malformed instructions are reported as an error.
+///
+/// ```
+/// use aoc17::day5::jumps_until_end;
+///
+/// assert!(jumps_until_end("0\n3\nx", |_| 1).is_err());
+/// ```
+pub fn jumps_until_end(s : &str, offset_rule : fn(isize) -> isize) -> Result<usize> {
+    Ok(count_jumps(&parse_instructions(s)?, offset_rule))
+}
+
+/// Return the list of jump instructions encoded in the string, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day5::parse_instructions;
+///
+/// assert_eq!(parse_instructions("0\n3\n-3"), Ok(vec![0, 3, -3]));
+/// assert_eq!(parse_instructions(""), Err(Error::EmptyInput { day: 5 }));
 /// ```
-pub fn jumps_until_end(s : &str, offset_rule : fn(isize) -> isize) -> usize {
-    let instructions : Vec<_> = s.lines().map(|x| x.parse::<isize>().unwrap()).collect();
+pub fn parse_instructions(s : &str) -> Result<Vec<isize>> {
+    let instructions = s.lines().enumerate().map(|(idx, line)| {
+        let token = line.trim();
+        token.parse::<isize>()
+             .map_err(|_| Error::parse(Day5::DAY, idx + 1, line, token, "an integer"))
+    }).collect::<Result<Vec<isize>>>()?;
+    if instructions.is_empty() {
+        return Err(Error::EmptyInput { day: Day5::DAY });
+    }
+
+    Ok(instructions)
+}
+
+/// Return the number of jumps it takes to exit the `instructions`.
+///
+/// See `jumps_until_end` for the meaning of `offset_rule`.
+pub fn count_jumps(instructions : &[isize], offset_rule : fn(isize) -> isize) -> usize {
     let ninstructions = instructions.len();
 
     let mut position : usize = 0;
@@ -50,3 +91,27 @@ pub fn jumps_until_end(s : &str, offset_rule : fn(isize) -> isize) -> usize {
 
     nexecuted
 }
+
+/// Solver for day 5.
+pub struct Day5;
+
+impl Solver for Day5 {
+    const DAY : usize = 5;
+
+    type Input = Vec<isize>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<Vec<isize>> {
+        parse_instructions(puzzle)
+    }
+
+    fn part1(&self, instructions : &Vec<isize>) -> usize {
+        count_jumps(instructions, |_| 1)
+    }
+
+    fn part2(&self, instructions : &Vec<isize>) -> usize {
+        // If the jump will be 3 or more, decrease the instruction by 1, otherwise increase by 1
+        count_jumps(instructions, |jump| if jump < 3 { 1 } else { -1 })
+    }
+}
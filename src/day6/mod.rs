@@ -1,6 +1,8 @@
 //! Solutions for day 6
 
-use std::collections::HashMap;
+use cycle;
+use error::{Error, Result};
+use solver::Solver;
 
 /// Return memory re-allocation information on `s`.
 ///
@@ -15,52 +17,113 @@ use std::collections::HashMap;
 /// ```
 /// use aoc17::day6::iterations_until_cycle;
 ///
-/// let (niterations, ninner) = iterations_until_cycle("0 2 7 0");
+/// let (niterations, ninner) = iterations_until_cycle("0 2 7 0").unwrap();
 /// assert_eq!(niterations, 5);
 /// assert_eq!(ninner, 4);
 /// ```
-pub fn iterations_until_cycle(s : &str) -> (usize, usize) {
-    let mut banks : Vec<_> = s.split_whitespace()
-                              .map(|x| x.parse::<usize>().unwrap())
-                              .collect();
-    let nbanks = banks.len();
-    let mut hm = HashMap::new();
-    let mut inner = 0;
-    loop {
-        // Find the index of the largest element.
-        // Takes the index of the first element in case of multiple elements having the largest
-        // value.
-        let mut max_index = 0;
-        let mut max = banks[max_index];
-        for (i, v) in banks.iter().enumerate().skip(1) {
-            if *v > max {
-                max = *v;
-                max_index = i;
-            }
+pub fn iterations_until_cycle(s : &str) -> Result<(usize, usize)> {
+    Ok(reallocation_cycle(&parse_banks(s)?))
+}
+
+/// Return the memory banks encoded in the whitespace-separated string.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day6::parse_banks;
+///
+/// assert_eq!(parse_banks("0 2 7 0"), Ok(vec![0, 2, 7, 0]));
+/// assert!(parse_banks("0 2 -7 0").is_err());
+/// ```
+pub fn parse_banks(s : &str) -> Result<Vec<usize>> {
+    let mut banks = Vec::new();
+    for (idx, line) in s.lines().enumerate() {
+        for token in line.split_whitespace() {
+            let bank = token.parse::<usize>()
+                            .map_err(|_| Error::parse(Day6::DAY, idx + 1, line, token,
+                                                      "a non-negative integer"))?;
+            banks.push(bank);
         }
+    }
+    if banks.is_empty() {
+        return Err(Error::EmptyInput { day: Day6::DAY });
+    }
+
+    Ok(banks)
+}
+
+/// Return the memory banks after redistributing the blocks of the largest bank.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day6::redistribute;
+///
+/// assert_eq!(redistribute(&[0, 2, 7, 0]), vec![2, 4, 1, 2]);
+/// assert_eq!(redistribute(&[2, 4, 1, 2]), vec![3, 1, 2, 3]);
+/// assert_eq!(redistribute(&[]), vec![]);
+/// ```
+pub fn redistribute(banks : &[usize]) -> Vec<usize> {
+    let mut banks = banks.to_vec();
+    let nbanks = banks.len();
+    if nbanks == 0 {
+        return banks;
+    }
 
-        // Redistribute the memory.
-        let mut memory = banks[max_index];
-        banks[max_index] -= memory;
-        for i in (0..nbanks).cycle().skip(max_index + 1) {
-            banks[i] += 1;
-            memory -= 1;
-            if memory == 0 {
-                break;
-            }
+    // Find the index of the largest element.
+    // Takes the index of the first element in case of multiple elements having the largest
+    // value.
+    let mut max_index = 0;
+    let mut max = banks[max_index];
+    for (i, v) in banks.iter().enumerate().skip(1) {
+        if *v > max {
+            max = *v;
+            max_index = i;
         }
+    }
 
-        // Insert into the hash map:
-        //   1. The memory layout of this iteration; and
-        //   2. The iteration number.
-        // If the memory layout is already in the map, `HashMap::insert` will return the previous
-        // value, i.e. the iteration number at which we first saw the current memory layout.
-        let nentries = hm.len();
-        if let Some(old) = hm.insert(banks.clone(), nentries) {
-            inner = nentries - old;
+    // Redistribute the memory.
+    let mut memory = banks[max_index];
+    banks[max_index] -= memory;
+    for i in (0..nbanks).cycle().skip(max_index + 1) {
+        if memory == 0 {
             break;
         }
+        banks[i] += 1;
+        memory -= 1;
+    }
+
+    banks
+}
+
+/// Return memory re-allocation information on the memory `banks`.
+///
+/// See `iterations_until_cycle` for the information returned.
+pub fn reallocation_cycle(banks : &[usize]) -> (usize, usize) {
+    let (prefix, length) = cycle::detect(banks.to_vec(), |banks| redistribute(banks));
+    (prefix + length, length)
+}
+
+/// Solver for day 6.
+pub struct Day6;
+
+impl Solver for Day6 {
+    const DAY : usize = 6;
+
+    type Input = (usize, usize);
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, puzzle : &str) -> Result<(usize, usize)> {
+        // Both parts come from the same cycle, so it is only found once
+        Ok(reallocation_cycle(&parse_banks(puzzle)?))
+    }
+
+    fn part1(&self, cycle : &(usize, usize)) -> usize {
+        cycle.0
     }
 
-    (hm.len() + 1, inner)
+    fn part2(&self, cycle : &(usize, usize)) -> usize {
+        cycle.1
+    }
 }
@@ -0,0 +1,323 @@
+//! Solutions for day 7.
+
+use std::collections::HashMap;
+
+use error::{Error, Result};
+use solver::Solver;
+
+/// A tower of programs, where each program holds up a disc of other programs.
+///
+/// Programs are stored in an arena and referred to by index, with each program's children stored
+/// as a list of indices, in the same way as `day12::Graph`.
+pub struct Tower {
+    names : Vec<String>,
+    weights : Vec<u64>,
+    children : Vec<Vec<usize>>,
+    parents : Vec<Option<usize>>,
+    index : HashMap<String, usize>
+}
+
+/// A change to the weight of a single program which balances the tower.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Correction {
+    /// The program whose weight is wrong.
+    pub program : usize,
+    /// The weight the program must have for the tower to be balanced.
+    pub weight : u64
+}
+
+impl Tower {
+    /// Return an empty tower.
+    fn new() -> Tower {
+        Tower {
+            names: Vec::new(),
+            weights: Vec::new(),
+            children: Vec::new(),
+            parents: Vec::new(),
+            index: HashMap::new()
+        }
+    }
+
+    /// Return the index of the program `name`, adding it with no weight if it is not yet known.
+    fn intern(&mut self, name : &str) -> usize {
+        if let Some(&idx) = self.index.get(name) {
+            return idx;
+        }
+        let idx = self.names.len();
+        self.names.push(name.to_string());
+        self.weights.push(0);
+        self.children.push(Vec::new());
+        self.parents.push(None);
+        self.index.insert(name.to_string(), idx);
+        idx
+    }
+
+    /// Return the number of programs in the tower.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Return true if the tower has no programs.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Return the index of the program called `name`, if there is one.
+    pub fn find(&self, name : &str) -> Option<usize> {
+        self.index.get(name).cloned()
+    }
+
+    /// Return the name of the `program`.
+    pub fn name(&self, program : usize) -> &str {
+        &self.names[program]
+    }
+
+    /// Return the weight of the `program` alone.
+    pub fn weight(&self, program : usize) -> u64 {
+        self.weights[program]
+    }
+
+    /// Return the programs held up by the `program`.
+    pub fn children(&self, program : usize) -> &[usize] {
+        &self.children[program]
+    }
+
+    /// Return the program holding up the `program`, or `None` if it is the root.
+    pub fn parent(&self, program : usize) -> Option<usize> {
+        self.parents[program]
+    }
+
+    /// Return the program at the bottom of the tower.
+    pub fn root(&self) -> usize {
+        // A valid tower has exactly one root, which is checked when parsing
+        self.parents.iter().position(|p| p.is_none()).unwrap()
+    }
+
+    /// Return the programs from `program` down to the root, inclusive.
+    pub fn path_to_root(&self, program : usize) -> Vec<usize> {
+        let mut path = vec![program];
+        while let Some(parent) = self.parents[*path.last().unwrap()] {
+            path.push(parent);
+        }
+        path
+    }
+
+    /// Return the number of programs between `program` and the root, which has depth 0.
+    pub fn depth(&self, program : usize) -> usize {
+        self.path_to_root(program).len() - 1
+    }
+
+    /// Return the programs in an order where every program comes after all of its children.
+    fn post_order(&self) -> Vec<usize> {
+        // Children are pushed after their parent in a pre-order walk, so reversing it puts them
+        // first
+        let mut order = Vec::with_capacity(self.len());
+        let mut stack = vec![self.root()];
+        while let Some(program) = stack.pop() {
+            order.push(program);
+            stack.extend(self.children[program].iter());
+        }
+        order.reverse();
+        order
+    }
+
+    /// Return the total weight of each program and everything it holds up, indexed by program.
+    pub fn subtree_weights(&self) -> Vec<u64> {
+        let mut totals = self.weights.clone();
+        for program in self.post_order() {
+            if let Some(parent) = self.parents[program] {
+                totals[parent] += totals[program];
+            }
+        }
+        totals
+    }
+
+    /// Return the correction to the weight of the single program that unbalances the tower.
+    ///
+    /// Returns `None` if the tower is already balanced, or if the unbalanced program cannot be
+    /// determined because a disc holds only two programs of different weights.
+    pub fn balance(&self) -> Option<Correction> {
+        let totals = self.subtree_weights();
+        let is_balanced = |program : usize| {
+            let children = &self.children[program];
+            children.iter().all(|&c| totals[c] == totals[children[0]])
+        };
+
+        let mut program = self.root();
+        let mut target : Option<u64> = None;
+        loop {
+            let children = &self.children[program];
+            if is_balanced(program) {
+                // Everything above this program is balanced, so this program must be the culprit
+                let target = target?;
+                let weight = self.weights[program] + target;
+                return weight.checked_sub(totals[program])
+                             .map(|weight| Correction { program, weight });
+            }
+            // Find the child whose total differs from the others. With only two children, pick
+            // the one whose own disc is unbalanced, as the other could be balanced either way
+            let odd = children.iter().cloned().find(|&c| {
+                let nsame = children.iter().filter(|&&d| totals[d] == totals[c]).count();
+                nsame == 1 && (children.len() > 2 || !is_balanced(c))
+            })?;
+            target = children.iter().map(|&c| totals[c]).find(|&t| t != totals[odd]);
+            program = odd;
+        }
+    }
+}
+
+/// Return the `Tower` described by the string.
+///
+/// Each line describes one program, its weight, and optionally the programs it holds up:
+///
+/// ```text
+/// fwft (72) -> ktlj, cntj, xhth
+/// ```
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day7::parse_tower;
+///
+/// let tower = parse_tower("a (1) -> b, c\nb (2)\nc (2)").unwrap();
+/// assert_eq!(tower.name(tower.root()), "a");
+/// assert_eq!(tower.subtree_weights()[tower.root()], 5);
+///
+/// assert!(parse_tower("a (1) -> b").is_err());
+/// assert!(parse_tower("a (1) -> b\nb (1) -> a").is_err());
+/// ```
+pub fn parse_tower(s : &str) -> Result<Tower> {
+    let mut tower = Tower::new();
+    // Where each program was first mentioned, and whether it has been described on its own line
+    let mut mentions : Vec<(usize, &str, &str)> = Vec::new();
+    let mut described : Vec<bool> = Vec::new();
+
+    for (idx, line) in s.lines().enumerate() {
+        let lineno = idx + 1;
+        let error = |token : &str, expected| Error::parse(Day7::DAY, lineno, line, token, expected);
+        if line.trim().is_empty() {
+            continue;
+        }
+        let mut halves = line.splitn(2, "->");
+        let mut program = halves.next().unwrap_or("").split_whitespace();
+        let name = program.next().unwrap_or("");
+        let weight = program.next().unwrap_or("");
+        if let Some(extra) = program.next() {
+            return Err(error(extra, "`->`"));
+        }
+        let weight = if weight.starts_with('(') && weight.ends_with(')') && weight.len() > 2 {
+            weight[1..weight.len() - 1].parse::<u64>().ok()
+        } else {
+            None
+        }.ok_or_else(|| error(weight, "a weight in parentheses"))?;
+
+        let node = tower.intern(name);
+        if node == mentions.len() {
+            mentions.push((lineno, line, name));
+            described.push(false);
+        }
+        if described[node] {
+            return Err(error(name, "a program not already described"));
+        }
+        described[node] = true;
+        tower.weights[node] = weight;
+
+        if let Some(children) = halves.next() {
+            for child in children.split(',') {
+                let child = child.trim();
+                if child.is_empty() {
+                    return Err(error(child, "a program name"));
+                }
+                let child_node = tower.intern(child);
+                if child_node == mentions.len() {
+                    mentions.push((lineno, line, child));
+                    described.push(false);
+                }
+                if tower.parents[child_node].is_some() || child_node == node {
+                    return Err(error(child, "a program not held by another"));
+                }
+                tower.parents[child_node] = Some(node);
+                tower.children[node].push(child_node);
+            }
+        }
+    }
+
+    if tower.is_empty() {
+        return Err(Error::EmptyInput { day: Day7::DAY });
+    }
+    if let Some(node) = described.iter().position(|d| !d) {
+        let (lineno, line, name) = mentions[node];
+        return Err(Error::parse(Day7::DAY, lineno, line, name,
+                                "a program described on its own line"));
+    }
+    let roots : Vec<&str> = (0..tower.len()).filter(|&p| tower.parents[p].is_none())
+                                           .map(|p| tower.name(p)).collect();
+    if roots.len() != 1 {
+        return Err(Error::Invalid {
+            day: Day7::DAY,
+            message: format!("expected exactly one bottom program, found {} ({})",
+                             roots.len(), roots.join(", "))
+        });
+    }
+    if tower.post_order().len() != tower.len() {
+        return Err(Error::Invalid {
+            day: Day7::DAY,
+            message: "some programs hold each other up in a loop".to_string()
+        });
+    }
+
+    Ok(tower)
+}
+
+/// Return the name of the program at the bottom of the tower, and the weight the single
+/// unbalancing program should have to balance the tower.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day7::bottom_and_balance;
+///
+/// assert_eq!(bottom_and_balance("pbga (66)
+/// xhth (57)
+/// ebii (61)
+/// havc (66)
+/// ktlj (57)
+/// fwft (72) -> ktlj, cntj, xhth
+/// qoyq (66)
+/// padx (45) -> pbga, havc, qoyq
+/// tknk (41) -> ugml, padx, fwft
+/// jptl (61)
+/// ugml (68) -> gyxo, ebii, jptl
+/// gyxo (61)
+/// cntj (57)"), Ok(("tknk".to_string(), Some(60))));
+/// ```
+pub fn bottom_and_balance(s : &str) -> Result<(String, Option<u64>)> {
+    let tower = parse_tower(s)?;
+    Ok((tower.name(tower.root()).to_string(), tower.balance().map(|c| c.weight)))
+}
+
+/// Solver for day 7.
+pub struct Day7;
+
+impl Solver for Day7 {
+    const DAY : usize = 7;
+
+    type Input = Tower;
+    type Answer1 = String;
+    type Answer2 = Result<u64>;
+
+    fn parse(&self, puzzle : &str) -> Result<Tower> {
+        parse_tower(puzzle)
+    }
+
+    fn part1(&self, tower : &Tower) -> String {
+        tower.name(tower.root()).to_string()
+    }
+
+    fn part2(&self, tower : &Tower) -> Result<u64> {
+        tower.balance().map(|correction| correction.weight).ok_or_else(|| Error::Invalid {
+            day: Self::DAY,
+            message: "no single correction balances the tower".to_string()
+        })
+    }
+}
@@ -2,6 +2,9 @@
 
 use std::collections::HashMap;
 
+use error::{Error, Result};
+use solver::Solver;
+
 /// Operations that return integers
 #[derive(Debug)]
 enum IntegerOperation {
@@ -40,6 +43,99 @@ fn apply_boolean_operation(op : &BooleanOperation, x : isize, y : isize) -> bool
     }
 }
 
+/// A single instruction, of the form `REGISTER OP NUM if REGISTER OP NUM`.
+///
+/// Register names are borrowed from the text the instruction was parsed from.
+#[derive(Debug)]
+pub struct Instruction<'a> {
+    register : &'a str,
+    operation : IntegerOperation,
+    amount : isize,
+    condition_register : &'a str,
+    condition : BooleanOperation,
+    condition_amount : isize
+}
+
+/// Return the instruction encoded in the string, the `lineno`th line of the puzzle.
+///
+/// See `registers` for the format of the instruction.
+fn parse_instruction<'a>(lineno : usize, instruction : &'a str) -> Result<Instruction<'a>> {
+    let mut components = instruction.split_whitespace();
+    // Return the next component, or an empty token if the line has ended
+    let mut next = || components.next().unwrap_or("");
+    let error = |token : &str, expected| {
+        Error::parse(Day8::DAY, lineno, instruction, token, expected)
+    };
+    let parse_num = |token : &str| token.parse::<isize>().map_err(|_| error(token, "an integer"));
+    let parse_register = |token : &'a str| if token.is_empty() {
+        Err(error(token, "a register name"))
+    } else {
+        Ok(token)
+    };
+
+    let register_a = parse_register(next())?;
+    // Convert the operations to enum variants
+    let op_a = match next() {
+        "inc" => IntegerOperation::Increment,
+        "dec" => IntegerOperation::Decrement,
+        other => return Err(error(other, "`inc` or `dec`"))
+    };
+    let num_a = parse_num(next())?;
+    match next() {
+        "if" => {},
+        other => return Err(error(other, "`if`"))
+    }
+    let register_b = parse_register(next())?;
+    let op_b = match next() {
+        ">" => BooleanOperation::GreaterThan,
+        "<" => BooleanOperation::LessThan,
+        ">=" => BooleanOperation::GreaterThanOrEqualTo,
+        "<=" => BooleanOperation::LessThanOrEqualTo,
+        "==" => BooleanOperation::EqualTo,
+        "!=" => BooleanOperation::NotEqualTo,
+        other => return Err(error(other, "a comparison operator"))
+    };
+    let num_b = parse_num(next())?;
+    match next() {
+        "" => {},
+        other => return Err(error(other, "end of line"))
+    }
+
+    Ok(Instruction {
+        register: register_a,
+        operation: op_a,
+        amount: num_a,
+        condition_register: register_b,
+        condition: op_b,
+        condition_amount: num_b
+    })
+}
+
+/// Return the list of instructions encoded in the string, one per line.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::day8::parse_instructions;
+///
+/// assert_eq!(parse_instructions("a inc 1 if b < 5").map(|i| i.len()), Ok(1));
+/// assert_eq!(parse_instructions("a inc 1 if b < 5\nb mul 2 if a > 1").unwrap_err(),
+///            Error::Parse { day: 8, line: 2, column: 3, token: "mul".to_string(),
+///                           expected: "`inc` or `dec`" });
+/// assert_eq!(parse_instructions("").unwrap_err(), Error::EmptyInput { day: 8 });
+/// ```
+pub fn parse_instructions(s : &str) -> Result<Vec<Instruction<'_>>> {
+    let instructions = s.lines().enumerate()
+                        .map(|(idx, line)| parse_instruction(idx + 1, line))
+                        .collect::<Result<Vec<Instruction>>>()?;
+    if instructions.is_empty() {
+        return Err(Error::EmptyInput { day: Day8::DAY });
+    }
+
+    Ok(instructions)
+}
+
 /// Return a hash table of registers and their values after running the instruction set.
 ///
 /// Also returns the highest register value reached during operations.
@@ -78,48 +174,42 @@ fn apply_boolean_operation(op : &BooleanOperation, x : isize, y : isize) -> bool
 /// assert_eq!(registers("b inc 5 if a > 1
 /// a inc 1 if b < 5
 /// c dec -10 if a >= 1
-/// c inc -20 if c == 10"), (expected, 10));
+/// c inc -20 if c == 10"), Ok((expected, 10)));
 /// ```
-pub fn registers(s : &str) -> (HashMap<&str, isize>, isize) {
+pub fn registers(s : &str) -> Result<(HashMap<&str, isize>, isize)> {
+    let instructions = parse_instructions(s)?;
+    Ok(execute(&instructions))
+}
+
+/// Return a hash table of registers and their values after running the `instructions`.
+///
+/// Also returns the highest register value reached during operations.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::day8::{execute, parse_instructions};
+///
+/// let s = "b inc 5 if a > 1\na inc 1 if b < 5";
+/// let instructions = parse_instructions(s).unwrap();
+/// let (registers, largest_seen) = execute(&instructions);
+/// assert_eq!((registers["a"], registers["b"], largest_seen), (1, 0, 1));
+/// ```
+pub fn execute<'a>(instructions : &[Instruction<'a>]) -> (HashMap<&'a str, isize>, isize) {
     let mut registers : HashMap<&str, isize> = HashMap::new();
     let mut largest_seen = 0;
-    for instruction in s.lines() {
-        let mut components = instruction.split_whitespace().collect::<Vec<_>>();
-        let num_b = components.pop().unwrap().parse::<isize>().unwrap();
-        let op_b_s = components.pop().unwrap();
-        let register_b = components.pop().unwrap();
-        // Ignore the 'if'
-        components.pop();
-        let num_a = components.pop().unwrap().parse::<isize>().unwrap();
-        let op_a_s = components.pop().unwrap();
-        let register_a = components.pop().unwrap();
-
-        // Convert the operations to enum variants
-        let op_a = match op_a_s {
-            "inc" => IntegerOperation::Increment,
-            "dec" => IntegerOperation::Decrement,
-            other => panic!("Could not decode operation {}", other)
-        };
-        let op_b = match op_b_s {
-            ">" => BooleanOperation::GreaterThan,
-            "<" => BooleanOperation::LessThan,
-            ">=" => BooleanOperation::GreaterThanOrEqualTo,
-            "<=" => BooleanOperation::LessThanOrEqualTo,
-            "==" => BooleanOperation::EqualTo,
-            "!=" => BooleanOperation::NotEqualTo,
-            other => panic!("Could not decode operation {}", other)
-        };
-
+    for instruction in instructions {
         let should_update;
         // Need to scope access to `registers` as we possibly need to mutate `register_b`, then use
         // the result to possibly mutate `register_a`; we cannot two mutable references at once.
         {
-            let rb = registers.entry(register_b).or_insert(0);
-            should_update = apply_boolean_operation(&op_b, *rb, num_b);
+            let rb = registers.entry(instruction.condition_register).or_insert(0);
+            should_update = apply_boolean_operation(&instruction.condition, *rb,
+                                                    instruction.condition_amount);
         }
-        let ra = registers.entry(register_a).or_insert(0);
+        let ra = registers.entry(instruction.register).or_insert(0);
         if should_update {
-            *ra = apply_integer_operation(&op_a, *ra, num_a);
+            *ra = apply_integer_operation(&instruction.operation, *ra, instruction.amount);
         }
 
         if *ra > largest_seen {
@@ -129,3 +219,38 @@ pub fn registers(s : &str) -> (HashMap<&str, isize>, isize) {
 
     (registers, largest_seen)
 }
+
+/// The largest register values reached by a set of instructions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Largest {
+    /// The largest value held by a register once all the instructions have run.
+    pub final_value : isize,
+    /// The largest value held by a register while the instructions were running.
+    pub seen : isize
+}
+
+/// Solver for day 8.
+pub struct Day8;
+
+impl Solver for Day8 {
+    const DAY : usize = 8;
+
+    type Input = Largest;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    fn parse(&self, puzzle : &str) -> Result<Largest> {
+        // The register names borrow from the puzzle, so run the instructions while it is at hand
+        let (registers, seen) = registers(puzzle)?;
+        let final_value = registers.values().max().cloned().unwrap_or(0);
+        Ok(Largest { final_value, seen })
+    }
+
+    fn part1(&self, largest : &Largest) -> isize {
+        largest.final_value
+    }
+
+    fn part2(&self, largest : &Largest) -> isize {
+        largest.seen
+    }
+}
@@ -1,30 +1,42 @@
 //! Solutions for day 9.
 
+use error::{Error, Result};
+use solver::Solver;
+
 /// Return the total score of a stream of groups and the number of garbage characters.
 ///
+/// Every group and piece of garbage must be closed, and groups may only be closed once opened.
+///
 /// # Examples
 ///
 /// ```
+/// use aoc17::Error;
 /// use aoc17::day9::stream_score;
 ///
-/// assert_eq!(stream_score("{}"), (1, 0));
-/// assert_eq!(stream_score("{{{}}}"), (6, 0));
-/// assert_eq!(stream_score("{{},{}}"), (5, 0));
-/// assert_eq!(stream_score("{{{},{},{{}}}}"), (16, 0));
-/// assert_eq!(stream_score("{<a>,<a>,<a>,<a>}"), (1, 4));
-/// assert_eq!(stream_score("{{<ab>},{<ab>},{<ab>},{<ab>}}"), (9, 8));
-/// assert_eq!(stream_score("{{<!!>},{<!!>},{<!!>},{<!!>}}"), (9, 0));
-/// assert_eq!(stream_score("{{<a!>},{<a!>},{<a!>},{<ab>}}"), (3, 17));
+/// assert_eq!(stream_score("{}"), Ok((1, 0)));
+/// assert_eq!(stream_score("{{{}}}"), Ok((6, 0)));
+/// assert_eq!(stream_score("{{},{}}"), Ok((5, 0)));
+/// assert_eq!(stream_score("{{{},{},{{}}}}"), Ok((16, 0)));
+/// assert_eq!(stream_score("{<a>,<a>,<a>,<a>}"), Ok((1, 4)));
+/// assert_eq!(stream_score("{{<ab>},{<ab>},{<ab>},{<ab>}}"), Ok((9, 8)));
+/// assert_eq!(stream_score("{{<!!>},{<!!>},{<!!>},{<!!>}}"), Ok((9, 0)));
+/// assert_eq!(stream_score("{{<a!>},{<a!>},{<a!>},{<ab>}}"), Ok((3, 17)));
+///
+/// assert_eq!(stream_score("<>"), Ok((0, 0)));
+/// assert_eq!(stream_score("<random characters>"), Ok((0, 17)));
+/// assert_eq!(stream_score("<<<<>"), Ok((0, 3)));
+/// assert_eq!(stream_score("<{!>}>"), Ok((0, 2)));
+/// assert_eq!(stream_score("<!!>"), Ok((0, 0)));
+/// assert_eq!(stream_score("<!!!>>"), Ok((0, 0)));
+/// assert_eq!(stream_score("<{o\"i!a,<{i<a>"), Ok((0, 10)));
 ///
-/// assert_eq!(stream_score("<>"), (0, 0));
-/// assert_eq!(stream_score("<random characters>"), (0, 17));
-/// assert_eq!(stream_score("<<<<>"), (0, 3));
-/// assert_eq!(stream_score("<{!>}>"), (0, 2));
-/// assert_eq!(stream_score("<!!>"), (0, 0));
-/// assert_eq!(stream_score("<!!!>>"), (0, 0));
-/// assert_eq!(stream_score("<{o\"i!a,<{i<a>"), (0, 10));
+/// assert_eq!(stream_score("{}}").err(),
+///            Some(Error::Parse { day: 9, line: 1, column: 3, token: "}".to_string(),
+///                                expected: "a group to close" }));
+/// assert!(stream_score("{<}").is_err());
+/// assert!(stream_score("{{}").is_err());
 /// ```
-pub fn stream_score(s : &str) -> (usize, usize) {
+pub fn stream_score(s : &str) -> Result<(usize, usize)> {
     let group_open = '{';
     let group_close = '}';
     let garbage_open = '<';
@@ -42,8 +54,15 @@ pub fn stream_score(s : &str) -> (usize, usize) {
     let mut skip = false;
     // Are we inside a garbage group?
     let mut inside_garbage = false;
+    // The line number and offset of the start of the line of the current character
+    let mut lineno = 1;
+    let mut line_start = 0;
 
-    for c in s.chars() {
+    for (idx, c) in s.char_indices() {
+        if c == '\n' {
+            lineno += 1;
+            line_start = idx + 1;
+        }
         if skip {
             skip = false;
             continue;
@@ -67,9 +86,51 @@ pub fn stream_score(s : &str) -> (usize, usize) {
             outer_score += 1;
             tot_score += outer_score;
         } else if c == group_close {
+            if outer_score == 0 {
+                let line = s[line_start..].lines().next().unwrap_or("");
+                let token = &s[idx..idx + 1];
+                return Err(Error::parse(Day9::DAY, lineno, line, token, "a group to close"));
+            }
             outer_score -= 1;
         }
     }
 
-    (tot_score, ngarbage_chars)
+    // The stream may only end once everything opened has been closed
+    let line = s[line_start..].lines().next().unwrap_or("");
+    let end = &line[line.len()..];
+    if inside_garbage {
+        return Err(Error::parse(Day9::DAY, lineno, line, end, "`>` closing the garbage"));
+    }
+    if outer_score > 0 {
+        return Err(Error::parse(Day9::DAY, lineno, line, end, "`}` closing the group"));
+    }
+
+    Ok((tot_score, ngarbage_chars))
+}
+
+/// Solver for day 9.
+pub struct Day9;
+
+impl Solver for Day9 {
+    const DAY : usize = 9;
+
+    type Input = String;
+    type Answer1 = Result<usize>;
+    type Answer2 = Result<usize>;
+
+    fn parse(&self, puzzle : &str) -> Result<String> {
+        if puzzle.trim().is_empty() {
+            return Err(Error::EmptyInput { day: Self::DAY });
+        }
+
+        Ok(puzzle.to_string())
+    }
+
+    fn part1(&self, stream : &String) -> Result<usize> {
+        stream_score(stream).map(|(score, _)| score)
+    }
+
+    fn part2(&self, stream : &String) -> Result<usize> {
+        stream_score(stream).map(|(_, ngarbage)| ngarbage)
+    }
 }
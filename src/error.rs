@@ -0,0 +1,112 @@
+//! Errors raised when solving puzzles.
+
+use std::error;
+use std::fmt;
+use std::result;
+
+/// Errors raised whilst parsing or solving a puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A token in the puzzle input could not be understood.
+    Parse {
+        /// The day whose input was being parsed.
+        day : usize,
+        /// The line number of the offending token, starting from 1.
+        line : usize,
+        /// The column number of the first character of the offending token, starting from 1.
+        column : usize,
+        /// The offending token, which is empty if the line ended unexpectedly.
+        token : String,
+        /// A description of what was expected in place of the token.
+        expected : &'static str
+    },
+    /// The puzzle input contained nothing to solve.
+    EmptyInput {
+        /// The day whose input was being parsed.
+        day : usize
+    },
+    /// The puzzle input was understood, but describes a puzzle that cannot be solved.
+    Invalid {
+        /// The day whose input was being parsed.
+        day : usize,
+        /// A description of the problem.
+        message : String
+    },
+    /// A line of an answers file could not be understood.
+    Answers {
+        /// The line number of the offending token, starting from 1.
+        line : usize,
+        /// The offending token.
+        token : String,
+        /// A description of what was expected in place of the token.
+        expected : &'static str
+    },
+    /// A file could not be read or written.
+    Io {
+        /// The location the input was being read from.
+        path : String,
+        /// A description of the underlying I/O error.
+        message : String
+    }
+}
+
+/// A `Result` whose error type is `Error`.
+pub type Result<T> = result::Result<T, Error>;
+
+impl Error {
+    /// Return a `Parse` error for `token` found on the `line`th line of some input, `text`.
+    ///
+    /// The `token` must be a sub-slice of `text`, from which its column is computed. An empty
+    /// `token` which is not a sub-slice of `text` is taken to mean that the line ended early.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::Error;
+    ///
+    /// let text = "a inc 1 if b ?? 2";
+    /// let err = Error::parse(8, 3, text, &text[13..15], "a comparison operator");
+    /// assert_eq!(err.to_string(),
+    ///            "day 8, line 3, column 14: expected a comparison operator, found `??`");
+    ///
+    /// let err = Error::parse(8, 3, text, "", "a comparison operator");
+    /// assert_eq!(err.to_string(),
+    ///            "day 8, line 3, column 18: expected a comparison operator, found end of line");
+    /// ```
+    pub fn parse(day : usize, line : usize, text : &str, token : &str,
+                 expected : &'static str) -> Error {
+        let start = text.as_ptr() as usize;
+        let offset = token.as_ptr() as usize;
+        let offset = if start <= offset && offset + token.len() <= start + text.len() {
+            offset - start
+        } else {
+            text.len()
+        };
+        let column = text[..offset].chars().count() + 1;
+        Error::Parse { day, line, column, token: token.to_string(), expected }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Error::Parse { day, line, column, ref token, expected } => {
+                write!(f, "day {}, line {}, column {}: expected {}, found ",
+                       day, line, column, expected)?;
+                if token.is_empty() {
+                    write!(f, "end of line")
+                } else {
+                    write!(f, "`{}`", token)
+                }
+            },
+            Error::EmptyInput { day } => write!(f, "day {}: puzzle input is empty", day),
+            Error::Invalid { day, ref message } => write!(f, "day {}: {}", day, message),
+            Error::Answers { line, ref token, expected } => {
+                write!(f, "answers file, line {}: expected {}, found `{}`", line, expected, token)
+            },
+            Error::Io { ref path, ref message } => write!(f, "could not read {}: {}", path, message)
+        }
+    }
+}
+
+impl error::Error for Error {}
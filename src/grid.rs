@@ -0,0 +1,391 @@
+//! Utilities shared by the days whose puzzles take place on a grid.
+
+use std::collections::{hash_map, HashMap};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A position on a grid, which can also be used as an offset between positions.
+pub trait GridCoordinate : Copy + Eq + Hash + Add<Output = Self> {
+    /// Return the origin of the grid.
+    fn origin() -> Self;
+
+    /// Return the offsets from a position to each of the positions one step away from it.
+    fn unit_steps() -> Vec<Self>;
+
+    /// Return the least number of steps needed to move from this position to `other`.
+    fn distance(&self, other : &Self) -> usize;
+}
+
+/// A position on a square grid, with the x-axis increasing to the right and the y-axis increasing
+/// upwards.
+#[derive(Debug,Hash,PartialEq,Eq,Copy,Clone)]
+pub struct Coordinate {
+    pub x : isize,
+    pub y : isize
+}
+
+impl Add for Coordinate {
+    type Output = Coordinate;
+
+    fn add(self, other : Coordinate) -> Coordinate {
+        Coordinate {
+            x: self.x + other.x,
+            y: self.y + other.y
+        }
+    }
+}
+
+/// Positions are one step apart if they differ by one along a single axis, so that distances are
+/// Manhattan distances.
+impl GridCoordinate for Coordinate {
+    fn origin() -> Coordinate {
+        Coordinate { x: 0, y: 0 }
+    }
+
+    fn unit_steps() -> Vec<Coordinate> {
+        vec![
+            Coordinate { x: 1, y: 0 },
+            Coordinate { x: 0, y: 1 },
+            Coordinate { x: -1, y: 0 },
+            Coordinate { x: 0, y: -1 }
+        ]
+    }
+
+    fn distance(&self, other : &Coordinate) -> usize {
+        ((self.x - other.x).abs() + (self.y - other.y).abs()) as usize
+    }
+}
+
+/// Return the positions one step away from `position`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::grid::Coordinate;
+/// use aoc17::grid::neighbours;
+///
+/// let n = neighbours(Coordinate { x: 1, y: 1 });
+/// assert_eq!(n.len(), 4);
+/// assert!(n.contains(&Coordinate { x: 1, y: 2 }));
+/// ```
+pub fn neighbours<C : GridCoordinate>(position : C) -> Vec<C> {
+    C::unit_steps().into_iter().map(|step| position + step).collect()
+}
+
+/// Return each position visited when taking `steps` from `start`, including `start`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::grid::Coordinate;
+/// use aoc17::grid::path;
+///
+/// let steps = vec![Coordinate { x: 1, y: 0 }, Coordinate { x: 0, y: 1 }];
+/// assert_eq!(path(Coordinate { x: 0, y: 0 }, steps),
+///            vec![Coordinate { x: 0, y: 0 }, Coordinate { x: 1, y: 0 },
+///                 Coordinate { x: 1, y: 1 }]);
+/// ```
+pub fn path<C, I>(start : C, steps : I) -> Vec<C>
+        where C : GridCoordinate, I : IntoIterator<Item = C> {
+    let mut positions = vec![start];
+    let mut position = start;
+    for step in steps {
+        position = position + step;
+        positions.push(position);
+    }
+    positions
+}
+
+/// Return the position reached by taking `steps` from `start`, and the furthest distance from
+/// `start` reached along the way.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::grid::Coordinate;
+/// use aoc17::grid::furthest_distance;
+///
+/// let steps = vec![Coordinate { x: 1, y: 0 }, Coordinate { x: 1, y: 0 },
+///                  Coordinate { x: -1, y: 0 }];
+/// assert_eq!(furthest_distance(Coordinate { x: 0, y: 0 }, steps), (Coordinate { x: 1, y: 0 }, 2));
+/// ```
+pub fn furthest_distance<C, I>(start : C, steps : I) -> (C, usize)
+        where C : GridCoordinate, I : IntoIterator<Item = C> {
+    let mut position = start;
+    let mut furthest = 0;
+    for step in steps {
+        position = position + step;
+        furthest = furthest.max(start.distance(&position));
+    }
+    (position, furthest)
+}
+
+/// One of the four directions on a square grid, using the axes of `Coordinate`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left
+}
+
+impl Direction {
+    /// Every direction, clockwise from up.
+    pub const ALL : [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left
+    ];
+
+    /// Return the direction a quarter turn anticlockwise from this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::grid::Direction;
+    ///
+    /// assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    /// assert_eq!(Direction::Left.turn_left(), Direction::Down);
+    /// ```
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    /// Return the direction a quarter turn clockwise from this one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::grid::Direction;
+    ///
+    /// assert_eq!(Direction::Up.turn_right(), Direction::Right);
+    /// assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    /// ```
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Return the opposite direction.
+    pub fn reverse(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Return the offset of a single step in this direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use aoc17::grid::Coordinate;
+    /// use aoc17::grid::Direction;
+    ///
+    /// assert_eq!(Direction::Up.step(), Coordinate { x: 0, y: 1 });
+    /// assert_eq!(Direction::Left.step(), Coordinate { x: -1, y: 0 });
+    /// ```
+    pub fn step(self) -> Coordinate {
+        match self {
+            Direction::Up => Coordinate { x: 0, y: 1 },
+            Direction::Right => Coordinate { x: 1, y: 0 },
+            Direction::Down => Coordinate { x: 0, y: -1 },
+            Direction::Left => Coordinate { x: -1, y: 0 }
+        }
+    }
+}
+
+/// A rectangular grid of cells.
+///
+/// Positions use the axes of `Coordinate`, with the origin at the bottom-left cell, so the
+/// top row of a grid of height `h` has `y = h - 1`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::grid::Coordinate;
+/// use aoc17::grid::Grid;
+///
+/// let grid = Grid::from_rows(vec![vec!['a', 'b'], vec!['c']], '.');
+/// assert_eq!((grid.width(), grid.height()), (2, 2));
+/// assert_eq!(grid.get(Coordinate { x: 0, y: 1 }), Some(&'a'));
+/// assert_eq!(grid.get(Coordinate { x: 1, y: 0 }), Some(&'.'));
+/// assert_eq!(grid.get(Coordinate { x: 2, y: 0 }), None);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width : usize,
+    height : usize,
+    /// The cells in row-major order, starting from the top row.
+    cells : Vec<T>
+}
+
+impl<T : Clone> Grid<T> {
+    /// Return a grid of `width` by `height` cells, all holding `fill`.
+    pub fn new(width : usize, height : usize, fill : T) -> Grid<T> {
+        Grid { width, height, cells: vec![fill; width*height] }
+    }
+
+    /// Return a grid made of `rows`, starting from the top row.
+    ///
+    /// The grid is as wide as the longest row, and shorter rows are padded with `fill`.
+    pub fn from_rows(rows : Vec<Vec<T>>, fill : T) -> Grid<T> {
+        let width = rows.iter().map(|row| row.len()).max().unwrap_or(0);
+        let height = rows.len();
+        let mut cells = Vec::with_capacity(width*height);
+        for mut row in rows {
+            row.resize(width, fill.clone());
+            cells.extend(row);
+        }
+        Grid { width, height, cells }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Return the number of columns in the grid.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Return the number of rows in the grid.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Return the index into `cells` of `position`, if it is inside the grid.
+    fn index(&self, position : Coordinate) -> Option<usize> {
+        if position.x < 0 || position.y < 0 {
+            return None;
+        }
+        let (x, y) = (position.x as usize, position.y as usize);
+        if x < self.width && y < self.height {
+            Some((self.height - 1 - y)*self.width + x)
+        } else {
+            None
+        }
+    }
+
+    /// Return true if `position` is inside the grid.
+    pub fn contains(&self, position : Coordinate) -> bool {
+        self.index(position).is_some()
+    }
+
+    /// Return the cell at `position`, if it is inside the grid.
+    pub fn get(&self, position : Coordinate) -> Option<&T> {
+        self.index(position).map(|idx| &self.cells[idx])
+    }
+
+    /// Return a mutable reference to the cell at `position`, if it is inside the grid.
+    pub fn get_mut(&mut self, position : Coordinate) -> Option<&mut T> {
+        self.index(position).map(move |idx| &mut self.cells[idx])
+    }
+
+    /// Return the rows of the grid, starting from the top row.
+    pub fn rows(&self) -> Vec<&[T]> {
+        if self.width == 0 {
+            return vec![&[]; self.height];
+        }
+        self.cells.chunks(self.width).collect()
+    }
+
+    /// Return the position of the first cell satisfying `predicate`, searching each row from left
+    /// to right, starting from the top row.
+    pub fn position<P : Fn(&T) -> bool>(&self, predicate : P) -> Option<Coordinate> {
+        self.cells.iter().position(predicate).map(|idx| Coordinate {
+            x: (idx % self.width) as isize,
+            y: (self.height - 1 - idx/self.width) as isize
+        })
+    }
+}
+
+/// A grid without bounds, which only stores the cells that have been set.
+///
+/// Rows are rendered from the top, in the same way as `Grid`, so `y` increases upwards.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::grid::Coordinate;
+/// use aoc17::grid::SparseGrid;
+///
+/// let mut grid = SparseGrid::new();
+/// grid.insert(Coordinate { x: -1, y: 2 }, 'a');
+/// grid.insert(Coordinate { x: 1, y: 1 }, 'b');
+/// assert_eq!(grid.bounds(), Some((Coordinate { x: -1, y: 1 }, Coordinate { x: 1, y: 2 })));
+/// assert_eq!(grid.render(|cell| cell.cloned().unwrap_or('.')), "a..\n..b");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells : HashMap<Coordinate, T>
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new() }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Return a grid with no cells set.
+    pub fn new() -> SparseGrid<T> {
+        SparseGrid::default()
+    }
+
+    /// Return the number of cells set.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Return true if no cells are set.
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Return the cell at `position`, if it is set.
+    pub fn get(&self, position : Coordinate) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    /// Set the cell at `position`, returning its previous value if it was set.
+    pub fn insert(&mut self, position : Coordinate, value : T) -> Option<T> {
+        self.cells.insert(position, value)
+    }
+
+    /// Unset the cell at `position`, returning its value if it was set.
+    pub fn remove(&mut self, position : Coordinate) -> Option<T> {
+        self.cells.remove(&position)
+    }
+
+    /// Return an iterator over the positions and values of the cells set, in no particular order.
+    pub fn iter(&self) -> hash_map::Iter<'_, Coordinate, T> {
+        self.cells.iter()
+    }
+
+    /// Return the bottom-left and top-right corners of the smallest rectangle containing every
+    /// cell set, or `None` if no cells are set.
+    pub fn bounds(&self) -> Option<(Coordinate, Coordinate)> {
+        let mut positions = self.cells.keys();
+        let first = *positions.next()?;
+        Some(positions.fold((first, first), |(min, max), p| {
+            (Coordinate { x: min.x.min(p.x), y: min.y.min(p.y) },
+             Coordinate { x: max.x.max(p.x), y: max.y.max(p.y) })
+        }))
+    }
+
+    /// Return the cells in the rectangle from the bottom-left corner `min` to the top-right corner
+    /// `max`, with one row per line starting from the top, and each cell shown as `show(cell)`.
+    pub fn render_region<F>(&self, min : Coordinate, max : Coordinate, show : F) -> String
+            where F : Fn(Option<&T>) -> char {
+        let mut rows = Vec::new();
+        for y in (min.y..max.y + 1).rev() {
+            let row = (min.x..max.x + 1).map(|x| show(self.get(Coordinate { x, y })));
+            rows.push(row.collect::<String>());
+        }
+        rows.join("\n")
+    }
+
+    /// Return the cells within the bounds of the grid, as `render_region`.
+    pub fn render<F : Fn(Option<&T>) -> char>(&self, show : F) -> String {
+        match self.bounds() {
+            Some((min, max)) => self.render_region(min, max, show),
+            None => String::new()
+        }
+    }
+}
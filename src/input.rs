@@ -0,0 +1,106 @@
+//! Loading of puzzle inputs.
+//!
+//! The input for a day is resolved, in order of preference, from:
+//!
+//! 1. A path given on the command line, where `-` means standard input;
+//! 2. The file `dayN.txt` inside the directory named by the `AOC_INPUT_DIR` environment variable;
+//! 3. The file `dayN.txt` inside the `inputs/` directory, relative to the working directory.
+
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use error::{Error, Result};
+use solver;
+
+/// The environment variable naming the directory that holds puzzle inputs.
+pub const INPUT_DIR_VAR : &str = "AOC_INPUT_DIR";
+
+/// The directory searched for puzzle inputs when no other location is given.
+pub const DEFAULT_INPUT_DIR : &str = "inputs";
+
+/// Where a puzzle input should be read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// Read the input from standard input.
+    Stdin,
+    /// Read the input from a file.
+    File(PathBuf)
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Source::Stdin => write!(f, "<stdin>"),
+            Source::File(ref path) => write!(f, "{}", path.display())
+        }
+    }
+}
+
+/// Return the name of the input file for `day`.
+pub fn file_name(day : usize) -> String {
+    format!("day{}.txt", day)
+}
+
+/// Return where the input for `day` should be read from.
+///
+/// # Arguments
+///
+/// * `day` - The day whose input is wanted
+/// * `arg` - The path given on the command line, if any, where `-` means standard input
+/// * `input_dir` - The directory given by the environment, if any
+///
+/// # Examples
+///
+/// ```
+/// use std::path::PathBuf;
+///
+/// use aoc17::input::{resolve, Source};
+///
+/// assert_eq!(resolve(8, Some("-"), Some("/tmp")), Source::Stdin);
+/// assert_eq!(resolve(8, Some("mine.txt"), Some("/tmp")), Source::File(PathBuf::from("mine.txt")));
+/// assert_eq!(resolve(8, None, Some("/tmp")), Source::File(PathBuf::from("/tmp/day8.txt")));
+/// assert_eq!(resolve(8, None, None), Source::File(PathBuf::from("inputs/day8.txt")));
+/// ```
+pub fn resolve(day : usize, arg : Option<&str>, input_dir : Option<&str>) -> Source {
+    match arg {
+        Some("-") => Source::Stdin,
+        Some(path) => Source::File(PathBuf::from(path)),
+        None => {
+            let dir = input_dir.unwrap_or(DEFAULT_INPUT_DIR);
+            Source::File(Path::new(dir).join(file_name(day)))
+        }
+    }
+}
+
+/// Return the contents of the `source`.
+pub fn read(source : &Source) -> Result<String> {
+    let mut puzzle = String::new();
+    let read = match *source {
+        Source::Stdin => io::stdin().read_to_string(&mut puzzle),
+        Source::File(ref path) => File::open(path).and_then(|mut f| f.read_to_string(&mut puzzle))
+    };
+    read.map_err(|err| Error::Io { path: source.to_string(), message: err.to_string() })?;
+
+    Ok(puzzle)
+}
+
+/// Return the input directory named by the environment, or the default input directory.
+pub fn input_dir() -> PathBuf {
+    env::var_os(INPUT_DIR_VAR).map_or_else(|| PathBuf::from(DEFAULT_INPUT_DIR), PathBuf::from)
+}
+
+/// Return the input for `day`, taking the input directory from the environment.
+///
+/// See `resolve` for the meaning of `arg`.
+pub fn load(day : usize, arg : Option<&str>) -> Result<String> {
+    let input_dir = env::var(INPUT_DIR_VAR).ok();
+    read(&resolve(day, arg, input_dir.as_deref()))
+}
+
+/// Return the input for `day` as `load`, or print the error and exit with a non-zero status.
+pub fn load_or_exit(day : usize, arg : Option<&str>) -> String {
+    solver::or_exit(load(day, arg))
+}
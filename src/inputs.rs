@@ -0,0 +1,227 @@
+//! A cache of puzzle inputs, kept separately for each user profile.
+//!
+//! Inputs are stored as `<root>/<profile>/2017/dayN.txt`, where the root is
+//! `$XDG_CACHE_HOME/aoc17` or, failing that, `~/.cache/aoc17`. Alongside each input is a
+//! `dayN.txt.hash` file holding the hash of the input when it was imported, so that inputs which
+//! have since been modified can be found with `Cache::verify`.
+
+use std::env;
+use std::fs::{self, File};
+use std::io::{Read, Write};
+use std::path::{Component, Path, PathBuf};
+
+use error::{Error, Result};
+use input::file_name;
+
+/// The year of the advent calendar whose inputs are cached.
+const YEAR : &str = "2017";
+
+/// The profile used when none is given.
+pub const DEFAULT_PROFILE : &str = "default";
+
+/// A cached puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// The profile the input belongs to.
+    pub profile : String,
+    /// The day the input is for.
+    pub day : usize
+}
+
+/// The result of checking a cached input against its hash.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The input matches the hash recorded when it was imported.
+    Verified,
+    /// The input has changed since it was imported.
+    Modified { expected : u64, found : u64 },
+    /// No hash was recorded for the input.
+    Unhashed
+}
+
+/// Return the 64-bit FNV-1a hash of `s`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::inputs::hash;
+///
+/// assert_eq!(hash(""), 0xcbf29ce484222325);
+/// assert_eq!(hash("a"), 0xaf63dc4c8601ec8c);
+/// ```
+pub fn hash(s : &str) -> u64 {
+    s.bytes().fold(0xcbf29ce484222325, |h, b| (h ^ u64::from(b)).wrapping_mul(0x100000001b3))
+}
+
+/// Return an `Io` error for `path`.
+fn io_error<E : ToString>(path : &Path, err : E) -> Error {
+    Error::Io { path: path.display().to_string(), message: err.to_string() }
+}
+
+/// Return the contents of the file at `path`.
+fn read_file(path : &Path) -> Result<String> {
+    let mut contents = String::new();
+    File::open(path).and_then(|mut f| f.read_to_string(&mut contents))
+                    .map_err(|err| io_error(path, err))?;
+    Ok(contents)
+}
+
+/// Write `contents` to the file at `path`, replacing anything already there.
+fn write_file(path : &Path, contents : &str) -> Result<()> {
+    File::create(path).and_then(|mut f| f.write_all(contents.as_bytes()))
+                      .map_err(|err| io_error(path, err))
+}
+
+/// Return the day encoded in an input file name such as `day8.txt`.
+fn parse_file_name(name : &str) -> Option<usize> {
+    if name.starts_with("day") && name.ends_with(".txt") {
+        name[3..name.len() - 4].parse().ok()
+    } else {
+        None
+    }
+}
+
+/// A directory of cached puzzle inputs.
+///
+/// # Examples
+///
+/// ```
+/// use std::{env, fs, process};
+///
+/// use aoc17::inputs::{Cache, Entry, Status};
+///
+/// let root = env::temp_dir().join(format!("aoc17-doctest-cache-{}", process::id()));
+/// let cache = Cache::new(&root);
+/// cache.import("alice", 6, "0 2 7 0").unwrap();
+///
+/// assert_eq!(cache.load("alice", 6), Ok("0 2 7 0".to_string()));
+/// assert_eq!(cache.list(), Ok(vec![Entry { profile: "alice".to_string(), day: 6 }]));
+/// assert_eq!(cache.verify("alice", 6), Ok(Status::Verified));
+///
+/// fs::remove_dir_all(&root).unwrap();
+/// ```
+pub struct Cache {
+    root : PathBuf
+}
+
+impl Cache {
+    /// Return a cache stored under the directory `root`.
+    pub fn new<P : Into<PathBuf>>(root : P) -> Cache {
+        Cache { root: root.into() }
+    }
+
+    /// Return the cache stored in the user's cache directory.
+    pub fn user() -> Result<Cache> {
+        let cache_dir = match env::var_os("XDG_CACHE_HOME") {
+            Some(dir) => PathBuf::from(dir),
+            None => match env::var_os("HOME") {
+                Some(home) => Path::new(&home).join(".cache"),
+                None => return Err(Error::Io {
+                    path: "~/.cache".to_string(),
+                    message: "neither XDG_CACHE_HOME nor HOME is set".to_string()
+                })
+            }
+        };
+        Ok(Cache::new(cache_dir.join("aoc17")))
+    }
+
+    /// Return the directory holding the inputs of `profile`.
+    ///
+    /// The profile name must be a single directory name, so that every profile is kept inside the
+    /// cache: names containing a path separator, `.`, `..` and absolute paths are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use aoc17::inputs::Cache;
+    ///
+    /// let cache = Cache::new("/cache");
+    /// assert_eq!(cache.profile_dir("alice"), Ok(Path::new("/cache/alice/2017").to_path_buf()));
+    /// assert!(cache.profile_dir("..").is_err());
+    /// assert!(cache.profile_dir("alice/../bob").is_err());
+    /// assert!(cache.profile_dir("/etc").is_err());
+    /// assert!(cache.profile_dir("").is_err());
+    /// ```
+    pub fn profile_dir(&self, profile : &str) -> Result<PathBuf> {
+        let mut components = Path::new(profile).components();
+        let single_name = match (components.next(), components.next()) {
+            (Some(Component::Normal(_)), None) => !profile.contains(['/', '\\']),
+            _ => false
+        };
+        if !single_name {
+            return Err(Error::Io {
+                path: profile.to_string(),
+                message: "a profile name must be a single directory name".to_string()
+            });
+        }
+        Ok(self.root.join(profile).join(YEAR))
+    }
+
+    /// Return the path of the cached input for `day` of `profile`.
+    pub fn path(&self, profile : &str, day : usize) -> Result<PathBuf> {
+        Ok(self.profile_dir(profile)?.join(file_name(day)))
+    }
+
+    /// Return the path of the hash recorded for the input for `day` of `profile`.
+    fn hash_path(&self, profile : &str, day : usize) -> Result<PathBuf> {
+        Ok(self.profile_dir(profile)?.join(format!("{}.hash", file_name(day))))
+    }
+
+    /// Store `puzzle` as the input for `day` of `profile`, replacing any existing input.
+    pub fn import(&self, profile : &str, day : usize, puzzle : &str) -> Result<()> {
+        let dir = self.profile_dir(profile)?;
+        fs::create_dir_all(&dir).map_err(|err| io_error(&dir, err))?;
+        write_file(&self.path(profile, day)?, puzzle)?;
+        write_file(&self.hash_path(profile, day)?, &format!("{:016x}\n", hash(puzzle)))
+    }
+
+    /// Return the cached input for `day` of `profile`.
+    pub fn load(&self, profile : &str, day : usize) -> Result<String> {
+        read_file(&self.path(profile, day)?)
+    }
+
+    /// Return all cached inputs, ordered by profile and then by day.
+    pub fn list(&self) -> Result<Vec<Entry>> {
+        let mut entries = Vec::new();
+        if !self.root.is_dir() {
+            return Ok(entries);
+        }
+        let profiles = fs::read_dir(&self.root).map_err(|err| io_error(&self.root, err))?;
+        for profile in profiles {
+            let profile = profile.map_err(|err| io_error(&self.root, err))?;
+            let profile = profile.file_name().to_string_lossy().into_owned();
+            let dir = match self.profile_dir(&profile) {
+                Ok(dir) if dir.is_dir() => dir,
+                _ => continue
+            };
+            for file in fs::read_dir(&dir).map_err(|err| io_error(&dir, err))? {
+                let file = file.map_err(|err| io_error(&dir, err))?;
+                if let Some(day) = parse_file_name(&file.file_name().to_string_lossy()) {
+                    entries.push(Entry { profile: profile.clone(), day });
+                }
+            }
+        }
+        entries.sort_by(|a, b| (&a.profile, a.day).cmp(&(&b.profile, b.day)));
+
+        Ok(entries)
+    }
+
+    /// Return whether the cached input for `day` of `profile` matches the hash recorded on import.
+    pub fn verify(&self, profile : &str, day : usize) -> Result<Status> {
+        let found = hash(&self.load(profile, day)?);
+        let hash_path = self.hash_path(profile, day)?;
+        if !hash_path.is_file() {
+            return Ok(Status::Unhashed);
+        }
+        let recorded = read_file(&hash_path)?;
+        let expected = u64::from_str_radix(recorded.trim(), 16)
+                           .map_err(|err| io_error(&hash_path, err))?;
+        if expected == found {
+            Ok(Status::Verified)
+        } else {
+            Ok(Status::Modified { expected, found })
+        }
+    }
+}
@@ -0,0 +1,218 @@
+//! Formatting of results as plain text or as machine-readable records.
+
+use std::fmt;
+use std::str::FromStr;
+
+use bench::format_seconds;
+
+/// The formats results can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// One human-readable line per record.
+    Plain,
+    /// A JSON array of objects, one per record.
+    Json,
+    /// Comma-separated values, with a header line.
+    Csv
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    /// Parse a format name: `plain`, `json` or `csv`.
+    fn from_str(s : &str) -> Result<Format, String> {
+        match s {
+            "plain" => Ok(Format::Plain),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            other => Err(format!("unknown format {}", other))
+        }
+    }
+}
+
+/// The outcome of solving a single part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    /// The part was solved; its answer was not checked.
+    Solved,
+    /// The answer matches the known-correct answer.
+    Pass,
+    /// The answer differs from the known-correct answer.
+    Fail,
+    /// The part was solved, but there is no known-correct answer to check against.
+    Unknown,
+    /// The puzzle input could not be loaded or solved.
+    Error
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f : &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            Status::Solved => "solved",
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Unknown => "unknown",
+            Status::Error => "error"
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The result of solving a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+    /// The day of the puzzle.
+    pub day : usize,
+    /// The part of the puzzle, 1 or 2.
+    pub part : usize,
+    /// The outcome of solving the part.
+    pub status : Status,
+    /// The answer found, if the part was solved.
+    pub answer : Option<String>,
+    /// The known-correct answer, if the answer was checked against it and differs.
+    pub expected : Option<String>,
+    /// The time taken to solve the part, excluding parsing, in seconds.
+    pub seconds : Option<f64>,
+    /// A description of the error, if the part could not be solved.
+    pub error : Option<String>
+}
+
+impl Record {
+    /// Return a record of solving `part` of `day`, giving `answer` after `seconds`.
+    pub fn solved(day : usize, part : usize, answer : String, seconds : f64) -> Record {
+        Record {
+            day,
+            part,
+            status: Status::Solved,
+            answer: Some(answer),
+            expected: None,
+            seconds: Some(seconds),
+            error: None
+        }
+    }
+
+    /// Return a record of failing to solve `part` of `day` because of `error`.
+    pub fn failed<E : fmt::Display>(day : usize, part : usize, error : E) -> Record {
+        Record {
+            day,
+            part,
+            status: Status::Error,
+            answer: None,
+            expected: None,
+            seconds: None,
+            error: Some(error.to_string())
+        }
+    }
+}
+
+/// Return `s` as a JSON string literal.
+fn json_string(s : &str) -> String {
+    let mut ret = String::with_capacity(s.len() + 2);
+    ret.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => ret.push_str("\\\""),
+            '\\' => ret.push_str("\\\\"),
+            '\n' => ret.push_str("\\n"),
+            '\t' => ret.push_str("\\t"),
+            c if (c as u32) < 0x20 => ret.push_str(&format!("\\u{:04x}", c as u32)),
+            c => ret.push(c)
+        }
+    }
+    ret.push('"');
+    ret
+}
+
+/// Return `s` as a CSV field, quoted if necessary.
+fn csv_field(s : &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+/// Return the plain-text line for `record`.
+fn plain(record : &Record) -> String {
+    let mut line = format!("Day {} part {}: ", record.day, record.part);
+    match record.status {
+        Status::Error => line.push_str("error"),
+        Status::Solved => {},
+        status => line.push_str(&format!("{} ", status))
+    }
+    if let Some(ref answer) = record.answer {
+        line.push_str(answer);
+    }
+    if let Some(ref expected) = record.expected {
+        line.push_str(&format!(" (expected {})", expected));
+    }
+    if let Some(seconds) = record.seconds {
+        line.push_str(&format!(" [{}]", format_seconds(seconds)));
+    }
+    if let Some(ref error) = record.error {
+        line.push_str(&format!(": {}", error));
+    }
+    line
+}
+
+/// Return the `records` written in the `format`.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::output::{render, Format, Record};
+///
+/// let records = vec![Record::solved(6, 1, "5".to_string(), 0.5),
+///                    Record::failed(9, 2, "could not read inputs/day9.txt")];
+///
+/// assert_eq!(render(Format::Plain, &records),
+///            "Day 6 part 1: 5 [500.000 ms]\n\
+///             Day 9 part 2: error: could not read inputs/day9.txt\n");
+/// assert_eq!(render(Format::Csv, &records),
+///            "day,part,status,answer,expected,seconds,error\n\
+///             6,1,solved,5,,0.5,\n\
+///             9,2,error,,,,could not read inputs/day9.txt\n");
+/// assert_eq!(render(Format::Json, &records),
+///            "[\n  {\"day\": 6, \"part\": 1, \"status\": \"solved\", \"answer\": \"5\", \
+///             \"expected\": null, \"seconds\": 0.5, \"error\": null},\n  \
+///             {\"day\": 9, \"part\": 2, \"status\": \"error\", \"answer\": null, \
+///             \"expected\": null, \"seconds\": null, \
+///             \"error\": \"could not read inputs/day9.txt\"}\n]\n");
+/// ```
+pub fn render(format : Format, records : &[Record]) -> String {
+    let mut out = String::new();
+    match format {
+        Format::Plain => {
+            for record in records {
+                out.push_str(&plain(record));
+                out.push('\n');
+            }
+        },
+        Format::Csv => {
+            out.push_str("day,part,status,answer,expected,seconds,error\n");
+            for r in records {
+                let field = |s : &Option<String>| s.as_ref().map_or(String::new(), |s| csv_field(s));
+                out.push_str(&format!("{},{},{},{},{},{},{}\n",
+                                      r.day, r.part, r.status, field(&r.answer), field(&r.expected),
+                                      r.seconds.map_or(String::new(), |s| format!("{:?}", s)),
+                                      field(&r.error)));
+            }
+        },
+        Format::Json => {
+            let field = |s : &Option<String>| s.as_ref().map_or("null".to_string(), |s| json_string(s));
+            let objects : Vec<String> = records.iter().map(|r| {
+                format!("  {{\"day\": {}, \"part\": {}, \"status\": \"{}\", \"answer\": {}, \
+                         \"expected\": {}, \"seconds\": {}, \"error\": {}}}",
+                        r.day, r.part, r.status, field(&r.answer), field(&r.expected),
+                        r.seconds.map_or("null".to_string(), |s| format!("{:?}", s)),
+                        field(&r.error))
+            }).collect();
+            if objects.is_empty() {
+                out.push_str("[]\n");
+            } else {
+                out.push_str(&format!("[\n{}\n]\n", objects.join(",\n")));
+            }
+        }
+    }
+    out
+}
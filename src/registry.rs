@@ -0,0 +1,71 @@
+//! Registry of the solutions to each day.
+
+use solver::Solution;
+use day1::Day1;
+use day2::Day2;
+use day3::Day3;
+use day4::Day4;
+use day5::Day5;
+use day6::Day6;
+use day7::Day7;
+use day8::Day8;
+use day9::Day9;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use day13::Day13;
+use day14::Day14;
+use day15::Day15;
+use day16::Day16;
+use day17::Day17;
+use day18::Day18;
+use day19::Day19;
+use day20::Day20;
+use day21::Day21;
+use day22::Day22;
+use day23::Day23;
+use day24::Day24;
+use day25::Day25;
+
+/// All known solutions, ordered by day.
+pub static SOLUTIONS : &[&dyn Solution] = &[
+    &Day1,
+    &Day2,
+    &Day3,
+    &Day4,
+    &Day5,
+    &Day6,
+    &Day7,
+    &Day8,
+    &Day9,
+    &Day10,
+    &Day11,
+    &Day12,
+    &Day13,
+    &Day14,
+    &Day15,
+    &Day16,
+    &Day17,
+    &Day18,
+    &Day19,
+    &Day20,
+    &Day21,
+    &Day22,
+    &Day23,
+    &Day24,
+    &Day25
+];
+
+/// Return the solution for `day`, if one exists.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::registry::solution;
+///
+/// assert_eq!(solution(1).map(|s| s.day()), Some(1));
+/// assert!(solution(26).is_none());
+/// ```
+pub fn solution(day : usize) -> Option<&'static dyn Solution> {
+    SOLUTIONS.iter().find(|s| s.day() == day).cloned()
+}
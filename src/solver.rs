@@ -0,0 +1,162 @@
+//! A common interface to the solutions of each day.
+
+use std::any::Any;
+use std::process;
+
+use error::Result;
+
+/// An answer to a puzzle.
+///
+/// Puzzles which have no answer for some inputs use a `Result` of an answer, so that the missing
+/// answer is reported as an error rather than shown or recorded as though it were an answer.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::Error;
+/// use aoc17::solver::Answer;
+///
+/// assert_eq!(42usize.render(), Ok("42".to_string()));
+/// let missing : Result<usize, Error> = Err(Error::EmptyInput { day: 7 });
+/// assert!(missing.render().is_err());
+/// ```
+pub trait Answer {
+    /// Return the answer as text, or the reason there is no answer.
+    fn render(&self) -> Result<String>;
+}
+
+/// Implement `Answer` for types whose answers are shown with `Display`.
+macro_rules! display_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn render(&self) -> Result<String> {
+                Ok(self.to_string())
+            }
+        })*
+    }
+}
+
+display_answer!(u32, u64, usize, i64, isize, String);
+
+impl<T : Answer> Answer for Result<T> {
+    fn render(&self) -> Result<String> {
+        match *self {
+            Ok(ref answer) => answer.render(),
+            Err(ref err) => Err(err.clone())
+        }
+    }
+}
+
+/// The solutions to both puzzles of a single day.
+///
+/// The puzzle input is parsed once, and the parsed representation is then shared by both parts.
+///
+/// # Examples
+///
+/// ```
+/// use aoc17::solver::Solver;
+/// use aoc17::day6::Day6;
+///
+/// let banks = Day6.parse("0 2 7 0").unwrap();
+/// assert_eq!(Day6.part1(&banks), 5);
+/// assert_eq!(Day6.part2(&banks), 4);
+/// ```
+pub trait Solver {
+    /// The day of the advent calendar this solver is for.
+    const DAY : usize;
+
+    /// The number of puzzles on this day, which is 2 on every day but the last.
+    const PARTS : usize = 2;
+
+    /// The parsed representation of the puzzle input.
+    type Input;
+    /// The answer to puzzle #1.
+    type Answer1 : Answer;
+    /// The answer to puzzle #2.
+    type Answer2 : Answer;
+
+    /// Return the parsed representation of the raw puzzle input.
+    fn parse(&self, puzzle : &str) -> Result<Self::Input>;
+
+    /// Return the answer to puzzle #1.
+    fn part1(&self, input : &Self::Input) -> Self::Answer1;
+
+    /// Return the answer to puzzle #2.
+    fn part2(&self, input : &Self::Input) -> Self::Answer2;
+}
+
+/// A type-erased `Solver`, so that solvers for different days can be stored together.
+///
+/// This is implemented for every `Solver`; there should be no need to implement it by hand.
+pub trait Solution : Sync {
+    /// Return the day of the advent calendar this solution is for.
+    fn day(&self) -> usize;
+
+    /// Return the number of puzzles on the day.
+    fn parts(&self) -> usize;
+
+    /// Return the parsed representation of the raw puzzle input.
+    fn parse(&self, puzzle : &str) -> Result<Box<dyn Any>>;
+
+    /// Return the answer to puzzle #1, given the output of `parse`.
+    fn part1(&self, input : &dyn Any) -> Result<String>;
+
+    /// Return the answer to puzzle #2, given the output of `parse`.
+    fn part2(&self, input : &dyn Any) -> Result<String>;
+
+    /// Return the answers to both puzzles for the raw puzzle input.
+    fn run(&self, puzzle : &str) -> Result<(String, String)> {
+        let input = self.parse(puzzle)?;
+        Ok((self.part1(&*input)?, self.part2(&*input)?))
+    }
+}
+
+impl<S> Solution for S where S : Solver + Sync, S::Input : 'static {
+    fn day(&self) -> usize {
+        S::DAY
+    }
+
+    fn parts(&self) -> usize {
+        S::PARTS
+    }
+
+    fn parse(&self, puzzle : &str) -> Result<Box<dyn Any>> {
+        let input = Solver::parse(self, puzzle)?;
+        Ok(Box::new(input))
+    }
+
+    fn part1(&self, input : &dyn Any) -> Result<String> {
+        Solver::part1(self, downcast::<S>(input)).render()
+    }
+
+    fn part2(&self, input : &dyn Any) -> Result<String> {
+        Solver::part2(self, downcast::<S>(input)).render()
+    }
+}
+
+/// Return the parsed input of `S` held by `input`.
+///
+/// Panics if `input` was not created by `S::parse`.
+fn downcast<S>(input : &dyn Any) -> &S::Input where S : Solver, S::Input : 'static {
+    input.downcast_ref().expect("Input was not parsed by this solution")
+}
+
+/// Return the value held by `result`, or print the error and exit with a non-zero status.
+///
+/// This is for the binaries of each day, which have nothing better to do with an error.
+pub fn or_exit<T>(result : Result<T>) -> T {
+    result.unwrap_or_else(|err| {
+        eprintln!("{}", err);
+        process::exit(1);
+    })
+}
+
+/// Print the answer to each puzzle of the day for the parsed `input`, as `Puzzle #N: ANSWER`.
+///
+/// If a puzzle has no answer, the reason is printed and the process exits with a non-zero status.
+pub fn print_answers<S : Solver>(solver : &S, input : &S::Input) {
+    println!("Puzzle #1: {}", or_exit(solver.part1(input).render()));
+    if S::PARTS > 1 {
+        println!("Puzzle #2: {}", or_exit(solver.part2(input).render()));
+    }
+}